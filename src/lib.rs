@@ -214,8 +214,13 @@ use serde_derive::{Deserialize, Serialize};
 use rstd::prelude::*;
 
 use codec::{Decode, Encode};
+use runtime_primitives::traits::{Hash, Saturating, Zero};
+use runtime_primitives::Perbill;
+use srml_support::traits::{Currency, ReservableCurrency};
 use srml_support::{decl_event, decl_module, decl_storage, dispatch, ensure};
 
+pub mod runtime_api;
+
 mod mock;
 mod tests;
 
@@ -263,13 +268,28 @@ impl InputValidationLengthConstraint {
 /// Constants
 /////////////////////////////////////////////////////////////////
 
-/// The greatest valid depth of a category.
-/// The depth of a root category is 0.
-const MAX_CATEGORY_DEPTH: u16 = 3;
+/// The greatest number of past titles kept in the history of a thread.
+/// When exceeded, the oldest title is dropped.
+const MAX_THREAD_TITLE_CHANGE_HISTORY_LENGTH: usize = 10;
+
+/// The greatest number of past titles and descriptions kept in the history of a category.
+/// When exceeded, the oldest ones are dropped.
+const MAX_CATEGORY_TEXT_CHANGE_HISTORY_LENGTH: usize = 10;
+
+/// The greatest number of threads which can be pinned in a category.
+const MAX_PINNED_THREADS_PER_CATEGORY: usize = 5;
+
+/// The greatest number of reports kept for a post.
+const MAX_REPORTS_PER_POST: usize = 20;
+
+/// The greatest number of threads or posts returned by a single paginated query.
+const MAX_QUERY_PAGE_SIZE: u32 = 100;
 
 /// Error messages for dispatchables
 const ERROR_FORUM_SUDO_NOT_SET: &str = "Forum sudo not set.";
 const ERROR_ORIGIN_NOT_FORUM_SUDO: &str = "Origin not forum sudo.";
+const ERROR_ORIGIN_NOT_FORUM_SUDO_OR_CATEGORY_MODERATOR: &str =
+    "Origin not forum sudo or category moderator.";
 const ERROR_CATEGORY_TITLE_TOO_SHORT: &str = "Category title too short.";
 const ERROR_CATEGORY_TITLE_TOO_LONG: &str = "Category title too long.";
 const ERROR_CATEGORY_DESCRIPTION_TOO_SHORT: &str = "Category description too long.";
@@ -279,6 +299,7 @@ const ERROR_ANCESTOR_CATEGORY_IMMUTABLE: &str =
 const ERROR_MAX_VALID_CATEGORY_DEPTH_EXCEEDED: &str = "Maximum valid category depth exceeded.";
 const ERROR_CATEGORY_DOES_NOT_EXIST: &str = "Category does not exist.";
 const ERROR_NOT_FORUM_USER: &str = "Not forum user.";
+const ERROR_FORUM_USER_NOT_ACTIVE: &str = "Forum user not active.";
 const ERROR_THREAD_TITLE_TOO_SHORT: &str = "Thread title too short.";
 const ERROR_THREAD_TITLE_TOO_LONG: &str = "Thread title too long.";
 const ERROR_POST_TEXT_TOO_SHORT: &str = "Post text too short.";
@@ -290,7 +311,77 @@ const ERROR_THREAD_ALREADY_MODERATED: &str = "Thread already moderated.";
 const ERROR_THREAD_MODERATED: &str = "Thread is moderated.";
 const ERROR_POST_DOES_NOT_EXIST: &str = "Post does not exist.";
 const ERROR_ACCOUNT_DOES_NOT_MATCH_POST_AUTHOR: &str = "Account does not match post author.";
+const ERROR_ACCOUNT_DOES_NOT_MATCH_THREAD_AUTHOR: &str = "Account does not match thread author.";
 const ERROR_POST_MODERATED: &str = "Post is moderated.";
+const ERROR_POST_DELETED: &str = "Post is deleted.";
+const ERROR_THREAD_DELETED: &str = "Thread is deleted.";
+const ERROR_POST_REACTION_NOT_CHANGED: &str = "Post reaction not changed.";
+const ERROR_POLL_QUESTION_TOO_SHORT: &str = "Poll question too short.";
+const ERROR_POLL_QUESTION_TOO_LONG: &str = "Poll question too long.";
+const ERROR_POLL_ALTERNATIVE_TEXT_TOO_SHORT: &str = "Poll alternative text too short.";
+const ERROR_POLL_ALTERNATIVE_TEXT_TOO_LONG: &str = "Poll alternative text too long.";
+const ERROR_POLL_TOO_FEW_ALTERNATIVES: &str = "Poll has too few alternatives.";
+const ERROR_POLL_TOO_MANY_ALTERNATIVES: &str = "Poll has too many alternatives.";
+const ERROR_POLL_END_NOT_IN_FUTURE: &str = "Poll end block not in the future.";
+const ERROR_THREAD_HAS_NO_POLL: &str = "Thread has no poll.";
+const ERROR_POLL_ENDED: &str = "Poll has ended.";
+const ERROR_POLL_ALTERNATIVE_DOES_NOT_EXIST: &str = "Poll alternative does not exist.";
+const ERROR_ALREADY_VOTED_ON_POLL: &str = "Account already voted on poll.";
+const ERROR_INSUFFICIENT_BALANCE_FOR_DEPOSIT: &str = "Insufficient balance for deposit.";
+const ERROR_THREAD_ALREADY_IN_CATEGORY: &str = "Thread already in category.";
+const ERROR_ORIGIN_NOT_THREAD_AUTHOR_OR_MODERATOR: &str =
+    "Origin not thread author, forum sudo or category moderator.";
+const ERROR_CATEGORY_ALREADY_IN_PARENT: &str = "Category already in parent.";
+const ERROR_CATEGORY_CANNOT_BE_MOVED_INTO_ITS_SUBTREE: &str =
+    "Category cannot be moved into its own subtree.";
+const ERROR_EXISTING_CATEGORY_EXCEEDS_MAX_CATEGORY_DEPTH: &str =
+    "Existing category exceeds maximum category depth.";
+const ERROR_REPLIED_POST_NOT_IN_THREAD: &str = "Replied post not in thread.";
+const ERROR_THREAD_ALREADY_PINNED: &str = "Thread already pinned.";
+const ERROR_THREAD_NOT_PINNED: &str = "Thread not pinned.";
+const ERROR_MAX_PINNED_THREADS_EXCEEDED: &str = "Maximum number of pinned threads exceeded.";
+const ERROR_PINNED_THREADS_ORDER_INVALID: &str =
+    "New order of pinned threads is not a reordering of pinned threads.";
+const ERROR_THREAD_LOCKED: &str = "Thread is locked.";
+const ERROR_THREAD_NOT_LOCKED: &str = "Thread is not locked.";
+const ERROR_THREAD_NOT_MODERATED: &str = "Thread is not moderated.";
+const ERROR_POST_NOT_MODERATED: &str = "Post is not moderated.";
+const ERROR_APPEAL_TEXT_TOO_SHORT: &str = "Appeal text too short.";
+const ERROR_APPEAL_TEXT_TOO_LONG: &str = "Appeal text too long.";
+const ERROR_ORIGIN_NOT_AUTHOR_OF_APPEAL_SUBJECT: &str =
+    "Origin is not author of moderated thread or post.";
+const ERROR_APPEAL_WINDOW_EXPIRED: &str = "Appeal window of moderation has expired.";
+const ERROR_MODERATION_ALREADY_APPEALED: &str = "Moderation already appealed.";
+const ERROR_APPEAL_DOES_NOT_EXIST: &str = "Appeal does not exist.";
+const ERROR_APPEAL_ALREADY_RESOLVED: &str = "Appeal already resolved.";
+const ERROR_ORIGIN_NOT_APPEAL_RESOLVER: &str = "Origin is not an appeal resolver.";
+const ERROR_APPEAL_RESOLVER_IS_ORIGINAL_MODERATOR: &str =
+    "Appeal resolver is moderator of appealed moderation.";
+const ERROR_APPEALED_MODERATION_ALREADY_REVERSED: &str = "Appealed moderation already reversed.";
+const ERROR_POST_REPORT_TEXT_TOO_SHORT: &str = "Post report text too short.";
+const ERROR_POST_REPORT_TEXT_TOO_LONG: &str = "Post report text too long.";
+const ERROR_POST_ALREADY_REPORTED_BY_ACCOUNT: &str = "Post already reported by account.";
+const ERROR_MAX_POST_REPORTS_EXCEEDED: &str = "Maximum number of reports of post exceeded.";
+const ERROR_POST_REPORT_THRESHOLD_TOO_HIGH: &str =
+    "Post report threshold exceeds maximum number of reports of post.";
+const ERROR_POST_RATE_LIMIT_EXCEEDED: &str = "Too many posts by account in rate limit period.";
+const ERROR_THREAD_RATE_LIMIT_EXCEEDED: &str = "Too few blocks since last thread by account.";
+const ERROR_ACCOUNT_BANNED: &str = "Account is banned from forum.";
+const ERROR_ACCOUNT_SUSPENDED: &str = "Account is suspended from forum.";
+const ERROR_ORIGIN_NOT_FORUM_SUDO_OR_ANY_CATEGORY_MODERATOR: &str =
+    "Origin not forum sudo or moderator of any category.";
+const ERROR_CANNOT_SANCTION_FORUM_SUDO: &str = "Forum sudo cannot be sanctioned.";
+const ERROR_CANNOT_SANCTION_CATEGORY_MODERATOR: &str =
+    "Category moderator can only be sanctioned by forum sudo.";
+const ERROR_SANCTION_NOT_IMPOSED_BY_ORIGIN: &str =
+    "Sanction of account was not imposed by origin, and origin is not forum sudo.";
+const ERROR_SANCTION_EXPIRY_NOT_IN_FUTURE: &str = "Sanction expiry not in future.";
+const ERROR_SUSPENSION_WITHOUT_EXPIRY: &str = "Suspension must have an expiry.";
+const ERROR_ACCOUNT_NOT_SANCTIONED: &str = "Account is not sanctioned.";
+const ERROR_THREADS_RESTRICTED_TO_MODERATORS: &str =
+    "Only forum sudo and moderators can create threads in category.";
+const ERROR_ACCOUNT_NOT_ON_CATEGORY_POSTING_ALLOWLIST: &str =
+    "Account not on posting allowlist of category.";
 const ERROR_POST_MODERATION_RATIONALE_TOO_SHORT: &str = "Post moderation rationale too short.";
 const ERROR_POST_MODERATION_RATIONALE_TOO_LONG: &str = "Post moderation rationale too long.";
 const ERROR_CATEGORY_NOT_BEING_UPDATED: &str = "Category not being updated.";
@@ -311,26 +402,42 @@ use system;
 use system::{ensure_root, ensure_signed};
 
 /// Represents a user in this forum.
-#[derive(Debug, Copy, Clone)]
-pub struct ForumUser<AccountId> {
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone)]
+pub struct ForumUser<AccountId, Role> {
     /// Identifier of user
-    pub id: AccountId, // In the future one could add things like
-                       // - updating post count of a user
-                       // - updating status (e.g. hero, new, etc.)
-                       //
+    pub id: AccountId,
+
+    /// Handle of user, for display.
+    pub handle: Vec<u8>,
+
+    /// Roles held by user outside of this forum, as defined by its registry.
+    pub roles: Vec<Role>,
+
+    /// Whether user is active, an inactive (e.g. suspended) user cannot act as a forum member.
+    pub is_active: bool,
+}
+
+impl<AccountId, Role: PartialEq> ForumUser<AccountId, Role> {
+    pub fn has_role(&self, role: &Role) -> bool {
+        self.roles.contains(role)
+    }
 }
 
 /// Represents a regsitry of `ForumUser` instances.
 pub trait ForumUserRegistry<AccountId> {
-    fn get_forum_user(id: &AccountId) -> Option<ForumUser<AccountId>>;
+    /// Role a user can hold in the registry.
+    type Role: PartialEq;
+
+    fn get_forum_user(id: &AccountId) -> Option<ForumUser<AccountId, Self::Role>>;
 }
 
 /// Convenient composite time stamp
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
 pub struct BlockchainTimestamp<BlockNumber, Moment> {
-    block: BlockNumber,
-    time: Moment,
+    pub block: BlockNumber,
+    pub time: Moment,
 }
 
 /// Represents a moderation outcome applied to a post or a thread.
@@ -338,13 +445,96 @@ pub struct BlockchainTimestamp<BlockNumber, Moment> {
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
 pub struct ModerationAction<BlockNumber, Moment, AccountId> {
     /// When action occured.
-    moderated_at: BlockchainTimestamp<BlockNumber, Moment>,
+    pub moderated_at: BlockchainTimestamp<BlockNumber, Moment>,
 
-    /// Account forum sudo which acted.
-    moderator_id: AccountId,
+    /// Account of forum sudo or category moderator which acted.
+    pub moderator_id: AccountId,
 
     /// Moderation rationale
-    rationale: Vec<u8>,
+    pub rationale: Vec<u8>,
+}
+
+/// Represents a moderation action which was later reversed.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct ReversedModerationAction<BlockNumber, Moment, AccountId> {
+    /// Moderation action which was reversed.
+    pub original: ModerationAction<BlockNumber, Moment, AccountId>,
+
+    /// Reversal of the moderation action, with the rationale for reversing it.
+    pub reversal: ModerationAction<BlockNumber, Moment, AccountId>,
+}
+
+/// Represents an appeal identifier
+pub type AppealId = u64;
+
+/// Represents the moderated thread or post an appeal is filed against.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq)]
+pub enum AppealSubject {
+    Thread(ThreadId),
+    Post(PostId),
+}
+
+impl Default for AppealSubject {
+    fn default() -> Self {
+        AppealSubject::Thread(0)
+    }
+}
+
+/// Represents the outcome of resolving an appeal.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq)]
+pub enum AppealOutcome {
+    /// Moderation is kept.
+    Upheld,
+
+    /// Moderation is reversed.
+    Overturned,
+}
+
+/// Represents the resolution of an appeal.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq)]
+pub struct AppealResolution<BlockNumber, Moment, AccountId> {
+    /// When appeal was resolved.
+    pub resolved_at: BlockchainTimestamp<BlockNumber, Moment>,
+
+    /// Account of appeal resolver which resolved the appeal.
+    pub resolver_id: AccountId,
+
+    /// Outcome of appeal.
+    pub outcome: AppealOutcome,
+
+    /// Resolution rationale
+    pub rationale: Vec<u8>,
+}
+
+/// Represents an appeal by an author against the moderation of their thread or post.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct Appeal<BlockNumber, Moment, AccountId> {
+    /// Appeal identifier
+    pub id: AppealId,
+
+    /// Moderated thread or post which is appealed.
+    pub subject: AppealSubject,
+
+    /// Number of reversed moderations of subject when appeal was filed,
+    /// which identifies the appealed moderation among all moderations of subject.
+    pub moderation_nr: u32,
+
+    /// Author of moderated thread or post, who filed the appeal.
+    pub appellant_id: AccountId,
+
+    /// Appeal text
+    pub text: Vec<u8>,
+
+    /// When appeal was filed.
+    pub filed_at: BlockchainTimestamp<BlockNumber, Moment>,
+
+    /// Resolution of appeal, if it has been resolved.
+    pub resolution: Option<AppealResolution<BlockNumber, Moment, AccountId>>,
 }
 
 /// Represents a revision of the text of a Post
@@ -352,97 +542,273 @@ pub struct ModerationAction<BlockNumber, Moment, AccountId> {
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
 pub struct PostTextChange<BlockNumber, Moment> {
     /// When this expiration occured
-    expired_at: BlockchainTimestamp<BlockNumber, Moment>,
+    pub expired_at: BlockchainTimestamp<BlockNumber, Moment>,
 
     /// Text that expired
-    text: Vec<u8>,
+    pub text: Vec<u8>,
+}
+
+/// Represents a revision of the title and description of a Category
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct CategoryTextChange<BlockNumber, Moment> {
+    /// When this expiration occured
+    pub expired_at: BlockchainTimestamp<BlockNumber, Moment>,
+
+    /// Title that expired
+    pub title: Vec<u8>,
+
+    /// Description that expired
+    pub description: Vec<u8>,
 }
 
 /// Represents a post identifier
 pub type PostId = u64;
 
+/// Represents a reaction of a forum member to a post.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq)]
+pub enum PostReaction {
+    Like,
+    Upvote,
+    Downvote,
+}
+
+/// Represents the reason given by a forum member for reporting a post.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq)]
+pub enum PostReportReason {
+    Spam,
+    Abuse,
+    OffTopic,
+    Other,
+}
+
+/// Represents a report of a post by a forum member.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq)]
+pub struct PostReport<BlockNumber, Moment, AccountId> {
+    /// Account which reported the post.
+    pub reporter_id: AccountId,
+
+    /// Reason for report
+    pub reason: PostReportReason,
+
+    /// Possible elaboration of reason
+    pub text: Option<Vec<u8>>,
+
+    /// When post was reported.
+    pub reported_at: BlockchainTimestamp<BlockNumber, Moment>,
+}
+
+/// Represents the kind of a forum sanction against an account.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq)]
+pub enum SanctionKind {
+    Ban,
+    Suspension,
+}
+
+/// Represents a sanction preventing an account from participating in the forum,
+/// regardless of its membership.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq)]
+pub struct Sanction<BlockNumber, AccountId> {
+    /// Kind of sanction
+    pub kind: SanctionKind,
+
+    /// Account of forum sudo or category moderator which imposed the sanction.
+    pub sanctioned_by: AccountId,
+
+    /// Block in which sanction was imposed.
+    pub sanctioned_at: BlockNumber,
+
+    /// Block from which sanction no longer applies, if it expires.
+    pub expires_at: Option<BlockNumber>,
+}
+
+impl<BlockNumber: PartialOrd, AccountId> Sanction<BlockNumber, AccountId> {
+    fn is_active(&self, current_block: &BlockNumber) -> bool {
+        match self.expires_at {
+            Some(ref expires_at) => current_block < expires_at,
+            None => true,
+        }
+    }
+}
+
 /// Represents a thread post
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
 pub struct Post<BlockNumber, Moment, AccountId> {
     /// Post identifier
-    id: PostId,
+    pub id: PostId,
 
     /// Id of thread to which this post corresponds.
-    thread_id: ThreadId,
+    pub thread_id: ThreadId,
 
     /// The post number of this post in its thread, i.e. total number of posts added (including this)
     /// to a thread when it was added.
     /// Is needed to give light clients assurance about getting all posts in a given range,
     // `created_at` is not sufficient.
     /// Starts at 1 for first post in thread.
-    nr_in_thread: u32,
+    pub nr_in_thread: u32,
+
+    /// The post number of this post among posts created by its author.
+    /// Starts at 1 for first post of author.
+    pub nr_by_author: u32,
 
     /// Current text of post
-    current_text: Vec<u8>,
+    pub current_text: Vec<u8>,
 
     /// Possible moderation of this post
-    moderation: Option<ModerationAction<BlockNumber, Moment, AccountId>>,
+    pub moderation: Option<ModerationAction<BlockNumber, Moment, AccountId>>,
 
     /// Edits of post ordered chronologically by edit time.
-    text_change_history: Vec<PostTextChange<BlockNumber, Moment>>,
+    pub text_change_history: Vec<PostTextChange<BlockNumber, Moment>>,
 
     /// When post was submitted.
-    created_at: BlockchainTimestamp<BlockNumber, Moment>,
+    pub created_at: BlockchainTimestamp<BlockNumber, Moment>,
 
     /// Author of post.
-    author_id: AccountId,
+    pub author_id: AccountId,
+
+    /// When post was deleted by its author, if it was.
+    /// The text of a deleted post is replaced by an empty tombstone,
+    /// and its edit history is cleared.
+    pub deleted_at: Option<BlockchainTimestamp<BlockNumber, Moment>>,
+
+    /// Post, in the same thread, to which this post is a reply, if any.
+    pub reply_to: Option<PostId>,
+
+    /// Number of posts which were added as a reply to this post.
+    pub num_replies: u32,
+
+    /// Past moderations of this post which were reversed, ordered chronologically.
+    pub moderation_history: Vec<ReversedModerationAction<BlockNumber, Moment, AccountId>>,
 }
 
 /// Represents a thread identifier
 pub type ThreadId = u64;
 
+/// Represents a revision of the title of a Thread
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct ThreadTitleChange<BlockNumber, Moment> {
+    /// When this expiration occured
+    pub expired_at: BlockchainTimestamp<BlockNumber, Moment>,
+
+    /// Title that expired
+    pub title: Vec<u8>,
+}
+
+/// Represents an alternative of a thread poll, along with its tally.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct PollAlternative {
+    /// Text of alternative
+    pub text: Vec<u8>,
+
+    /// Number of votes cast for this alternative
+    pub vote_count: u32,
+}
+
+/// Represents a poll attached to a thread
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct Poll<BlockNumber> {
+    /// Question of poll
+    pub question: Vec<u8>,
+
+    /// Alternatives which can be voted for, in the order they were provided.
+    pub alternatives: Vec<PollAlternative>,
+
+    /// Block at which poll ends, votes are accepted in all blocks before it.
+    pub ends_at: BlockNumber,
+}
+
+/// Represents the poll to attach to a thread when it is created
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct PollInput<BlockNumber> {
+    /// Question of poll
+    pub question: Vec<u8>,
+
+    /// Texts of alternatives
+    pub alternatives: Vec<Vec<u8>>,
+
+    /// Block at which poll ends
+    pub ends_at: BlockNumber,
+}
+
 /// Represents a thread
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
 pub struct Thread<BlockNumber, Moment, AccountId> {
     /// Thread identifier
-    id: ThreadId,
+    pub id: ThreadId,
 
     /// Title
-    title: Vec<u8>,
+    pub title: Vec<u8>,
 
     /// Category in which this thread lives
-    category_id: CategoryId,
+    pub category_id: CategoryId,
 
     /// The thread number of this thread in its category, i.e. total number of thread added (including this)
     /// to a category when it was added.
     /// Is needed to give light clients assurance about getting all threads in a given range,
     /// `created_at` is not sufficient.
     /// Starts at 1 for first thread in category.
-    nr_in_category: u32,
+    pub nr_in_category: u32,
+
+    /// The thread number of this thread among threads created by its author.
+    /// Starts at 1 for first thread of author.
+    pub nr_by_author: u32,
 
     /// Possible moderation of this thread
-    moderation: Option<ModerationAction<BlockNumber, Moment, AccountId>>,
+    pub moderation: Option<ModerationAction<BlockNumber, Moment, AccountId>>,
 
-    /// Number of unmoderated and moderated posts in this thread.
-    /// The sum of these two only increases, and former is incremented
+    /// Number of unmoderated, moderated and deleted posts in this thread.
+    /// The sum of these three only increases, and first is incremented
     /// for each new post added to this thread. A new post is added
     /// with a `nr_in_thread` equal to this sum
     ///
-    /// When there is a moderation
+    /// When there is a moderation or a deletion by author
     /// of a post, the variables are incremented and decremented, respectively.
     ///
     /// These values are vital for light clients, in order to validate that they are
     /// not being censored from posts in a thread.
-    num_unmoderated_posts: u32,
-    num_moderated_posts: u32,
+    pub num_unmoderated_posts: u32,
+    pub num_moderated_posts: u32,
+    pub num_deleted_posts: u32,
 
     /// When thread was established.
-    created_at: BlockchainTimestamp<BlockNumber, Moment>,
+    pub created_at: BlockchainTimestamp<BlockNumber, Moment>,
 
     /// Author of post.
-    author_id: AccountId,
+    pub author_id: AccountId,
+
+    /// When thread was deleted by its author, if it was.
+    /// The title of a deleted thread is replaced by an empty tombstone.
+    pub deleted_at: Option<BlockchainTimestamp<BlockNumber, Moment>>,
+
+    /// Possible poll attached to thread at creation.
+    pub poll: Option<Poll<BlockNumber>>,
+
+    /// Most recent edits of title ordered chronologically by edit time,
+    /// at most `MAX_THREAD_TITLE_CHANGE_HISTORY_LENGTH` of them.
+    pub title_change_history: Vec<ThreadTitleChange<BlockNumber, Moment>>,
+
+    /// Whether thread is locked by a moderator, in which case no posts
+    /// can be added or edited, while the thread remains unmoderated.
+    pub locked: bool,
+
+    /// Past moderations of this thread which were reversed, ordered chronologically.
+    pub moderation_history: Vec<ReversedModerationAction<BlockNumber, Moment, AccountId>>,
 }
 
 impl<BlockNumber, Moment, AccountId> Thread<BlockNumber, Moment, AccountId> {
     fn num_posts_ever_created(&self) -> u32 {
-        self.num_unmoderated_posts + self.num_moderated_posts
+        self.num_unmoderated_posts + self.num_moderated_posts + self.num_deleted_posts
     }
 }
 
@@ -454,11 +820,11 @@ pub type CategoryId = u64;
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
 pub struct ChildPositionInParentCategory {
     /// Id of parent category
-    parent_id: CategoryId,
+    pub parent_id: CategoryId,
 
     /// Nr of the child in the parent
     /// Starts at 1
-    child_nr_in_parent_category: u32,
+    pub child_nr_in_parent_category: u32,
 }
 
 /// Represents a category
@@ -466,54 +832,122 @@ pub struct ChildPositionInParentCategory {
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
 pub struct Category<BlockNumber, Moment, AccountId> {
     /// Category identifier
-    id: CategoryId,
+    pub id: CategoryId,
 
     /// Title
-    title: Vec<u8>,
+    pub title: Vec<u8>,
 
     /// Description
-    description: Vec<u8>,
+    pub description: Vec<u8>,
 
     /// When category was established.
-    created_at: BlockchainTimestamp<BlockNumber, Moment>,
+    pub created_at: BlockchainTimestamp<BlockNumber, Moment>,
 
     /// Whether category is deleted.
-    deleted: bool,
+    pub deleted: bool,
 
     /// Whether category is archived.
-    archived: bool,
+    pub archived: bool,
 
     /// Number of subcategories (deleted, archived or neither),
-    /// unmoderated threads and moderated threads, _directly_ in this category.
+    /// unmoderated threads, moderated threads, deleted threads and
+    /// threads moved to another category, _directly_ in this category.
     ///
-    /// As noted, the first is unaffected by any change in state of direct subcategory.
+    /// As noted, the first is unaffected by any change in state of direct subcategory,
+    /// but it is decremented when a direct subcategory is moved to another parent.
+    /// Child numbers of subcategories start at 1.
     ///
-    /// The sum of the latter two only increases, and former is incremented
-    /// for each new thread added to this category. A new thread is added
+    /// The sum of the latter four only increases, and first of them is incremented
+    /// for each new thread added, or moved, to this category. A new thread is added
     /// with a `nr_in_category` equal to this sum.
     ///
-    /// When there is a moderation
+    /// When there is a moderation, a deletion by author or a move
     /// of a thread, the variables are incremented and decremented, respectively.
     ///
     /// These values are vital for light clients, in order to validate that they are
     /// not being censored from subcategories or threads in a category.
-    num_direct_subcategories: u32,
-    num_direct_unmoderated_threads: u32,
-    num_direct_moderated_threads: u32,
+    pub num_direct_subcategories: u32,
+    pub num_direct_unmoderated_threads: u32,
+    pub num_direct_moderated_threads: u32,
+    pub num_direct_deleted_threads: u32,
+    pub num_direct_moved_threads: u32,
+
+    /// Number of subcategories which were moved to another parent category.
+    /// The sum of this and `num_direct_subcategories` only increases, and a new
+    /// subcategory is added with a `child_nr_in_parent_category` equal to this sum.
+    pub num_direct_moved_subcategories: u32,
 
     /// Position as child in parent, if present, otherwise this category is a root category
-    position_in_parent_category: Option<ChildPositionInParentCategory>,
+    pub position_in_parent_category: Option<ChildPositionInParentCategory>,
 
     /// Account of the moderator which created category.
-    moderator_id: AccountId,
+    pub moderator_id: AccountId,
+
+    /// Most recent edits of title and description ordered chronologically by edit time,
+    /// at most `MAX_CATEGORY_TEXT_CHANGE_HISTORY_LENGTH` of them.
+    pub text_change_history: Vec<CategoryTextChange<BlockNumber, Moment>>,
 }
 
 impl<BlockNumber, Moment, AccountId> Category<BlockNumber, Moment, AccountId> {
+    fn num_subcategories_created(&self) -> u32 {
+        self.num_direct_subcategories + self.num_direct_moved_subcategories
+    }
+
     fn num_threads_created(&self) -> u32 {
-        self.num_direct_unmoderated_threads + self.num_direct_moderated_threads
+        self.num_direct_unmoderated_threads
+            + self.num_direct_moderated_threads
+            + self.num_direct_deleted_threads
+            + self.num_direct_moved_threads
+    }
+}
+
+/// Represents who may create threads and posts directly in a category,
+/// in addition to forum sudo and moderators of the category.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq)]
+pub enum PostingPolicy {
+    /// Any forum member may create threads and posts.
+    Open,
+
+    /// Any forum member may add posts, but only moderators create threads.
+    ModeratorsOnlyThreads,
+
+    /// Only forum members on the posting allowlist of the category may create threads and posts.
+    Restricted,
+}
+
+impl Default for PostingPolicy {
+    fn default() -> Self {
+        PostingPolicy::Open
     }
 }
 
+/// Represents the counters of a category, as exposed to read queries.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct CategoryCounters {
+    pub num_direct_subcategories: u32,
+    pub num_direct_unmoderated_threads: u32,
+    pub num_direct_moderated_threads: u32,
+    pub num_direct_deleted_threads: u32,
+    pub num_direct_moved_threads: u32,
+
+    /// Greatest number given to a thread in the category so far.
+    pub num_threads_created: u32,
+}
+
+/// Represents the counters of a thread, as exposed to read queries.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct ThreadCounters {
+    pub num_unmoderated_posts: u32,
+    pub num_moderated_posts: u32,
+    pub num_deleted_posts: u32,
+
+    /// Greatest number given to a post in the thread so far.
+    pub num_posts_ever_created: u32,
+}
+
 /// Represents a sequence of categories which have child-parent relatioonship
 /// where last element is final ancestor, or root, in the context of the category tree.
 type CategoryTreePath<BlockNumber, Moment, AccountId> =
@@ -523,8 +957,21 @@ pub trait Trait: system::Trait + timestamp::Trait + Sized {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
     type MembershipRegistry: ForumUserRegistry<Self::AccountId>;
+
+    /// Currency from which thread and post deposits are reserved.
+    type Currency: ReservableCurrency<Self::AccountId>;
 }
 
+/// Balance type of the currency used for thread and post deposits.
+pub type BalanceOf<T> =
+    <<T as Trait>::Currency as Currency<<T as system::Trait>::AccountId>>::Balance;
+
+/// Forum user type of the membership registry.
+pub type ForumUserOf<T> = ForumUser<
+    <T as system::Trait>::AccountId,
+    <<T as Trait>::MembershipRegistry as ForumUserRegistry<<T as system::Trait>::AccountId>>::Role,
+>;
+
 decl_storage! {
     trait Store for Module<T: Trait> as Forum {
 
@@ -534,21 +981,73 @@ decl_storage! {
         /// Category identifier value to be used for the next Category created.
         pub NextCategoryId get(next_category_id) config(): CategoryId;
 
+        /// Map parent category identifier and child number in parent to corresponding subcategory identifier.
+        /// Numbers of subcategories moved out of the parent have no entry.
+        pub CategoryIdByParentAndNr get(category_id_by_parent_and_nr): double_map CategoryId, blake2_256(u32) => Option<CategoryId>;
+
+        /// Identifiers of root categories, in the order they became root categories.
+        pub RootCategoryIds get(root_category_ids): Vec<CategoryId>;
+
+        /// Map category identifier to posting policy of the category.
+        /// Policies are not inherited by subcategories.
+        pub PostingPolicyByCategory get(posting_policy_by_category): map CategoryId => PostingPolicy;
+
+        /// Map category identifier and account to whether account is on the posting allowlist of the category.
+        pub CategoryPostingAllowlist get(is_on_category_posting_allowlist): double_map CategoryId, blake2_256(T::AccountId) => bool;
+
+        /// Map account and thread number by author to corresponding thread identifier.
+        /// Numbers of deleted threads have no entry.
+        pub ThreadIdByAuthorAndNr get(thread_id_by_author_and_nr): double_map T::AccountId, blake2_256(u32) => Option<ThreadId>;
+
+        /// Map account to number of threads ever created by the account.
+        pub NumThreadsCreatedByAuthor get(num_threads_created_by_author): map T::AccountId => u32;
+
+        /// Map account and post number by author to corresponding post identifier.
+        /// Numbers of deleted posts have no entry.
+        pub PostIdByAuthorAndNr get(post_id_by_author_and_nr): double_map T::AccountId, blake2_256(u32) => Option<PostId>;
+
+        /// Map account to number of posts ever created by the account.
+        pub NumPostsCreatedByAuthor get(num_posts_created_by_author): map T::AccountId => u32;
+
         /// Map thread identifier to corresponding thread.
         pub ThreadById get(thread_by_id) config(): map ThreadId => Thread<T::BlockNumber, T::Moment, T::AccountId>;
 
         /// Thread identifier value to be used for next Thread in threadById.
         pub NextThreadId get(next_thread_id) config(): ThreadId;
 
+        /// Map category identifier and thread number in category to corresponding thread identifier.
+        /// Numbers of threads moved out of the category have no entry.
+        pub ThreadIdByCategoryAndNr get(thread_id_by_category_and_nr): double_map CategoryId, blake2_256(u32) => Option<ThreadId>;
+
         /// Map post identifier to corresponding post.
         pub PostById get(post_by_id) config(): map PostId => Post<T::BlockNumber, T::Moment, T::AccountId>;
 
         /// Post identifier value to be used for for next post created.
         pub NextPostId get(next_post_id) config(): PostId;
 
+        /// Map thread identifier and post number in thread to corresponding post identifier.
+        pub PostIdByThreadAndNr get(post_id_by_thread_and_nr): double_map ThreadId, blake2_256(u32) => Option<PostId>;
+
+        /// Map post identifier and account to the reaction of the account to the post, if any.
+        pub PostReactionByAccount get(post_reaction_by_account): double_map PostId, blake2_256(T::AccountId) => Option<PostReaction>;
+
+        /// Map post identifier and reaction to the number of accounts currently having that reaction to the post.
+        pub PostReactionCount get(post_reaction_count): double_map PostId, blake2_256(PostReaction) => u32;
+
         /// Account of forum sudo.
         pub ForumSudo get(forum_sudo) config(): Option<T::AccountId>;
 
+        /// The greatest valid depth of a category.
+        /// The depth of a root category is 0.
+        pub MaxCategoryDepth get(max_category_depth) config(): u16;
+
+        /// Map category identifier and account to whether account is a moderator of the category.
+        /// Moderation rights of a category are inherited by all of its subcategories.
+        pub CategoryModerators get(is_category_moderator): double_map CategoryId, blake2_256(T::AccountId) => bool;
+
+        /// Map account to number of categories it is directly a moderator of.
+        pub NumModeratedCategoriesByAccount get(num_moderated_categories_by_account): map T::AccountId => u32;
+
         /// Input constraints
         /// These are all forward looking, that is they are enforced on all
         /// future calls.
@@ -558,6 +1057,124 @@ decl_storage! {
         pub PostTextConstraint get(post_text_constraint) config(): InputValidationLengthConstraint;
         pub ThreadModerationRationaleConstraint get(thread_moderation_rationale_constraint) config(): InputValidationLengthConstraint;
         pub PostModerationRationaleConstraint get(post_moderation_rationale_constraint) config(): InputValidationLengthConstraint;
+        pub PollQuestionConstraint get(poll_question_constraint) config(): InputValidationLengthConstraint;
+        pub PollAlternativeTextConstraint get(poll_alternative_text_constraint) config(): InputValidationLengthConstraint;
+
+        /// Constraint on the number of alternatives in a poll.
+        pub PollAlternativesConstraint get(poll_alternatives_constraint) config(): InputValidationLengthConstraint;
+
+        /// Deposit reserved from the author of a new thread, in addition
+        /// to the deposit for its initial post.
+        pub ThreadDeposit get(thread_deposit) config(): BalanceOf<T>;
+
+        /// Deposit reserved from the author of a new post.
+        pub PostDeposit get(post_deposit) config(): BalanceOf<T>;
+
+        /// Fraction of deposit which is slashed when a thread or post is moderated,
+        /// the remainder is returned to the author.
+        pub ModerationDepositSlashRatio get(moderation_deposit_slash_ratio) config(): Perbill;
+
+        /// Map thread identifier to deposit currently reserved for the thread.
+        pub ThreadDepositById get(thread_deposit_by_id): map ThreadId => BalanceOf<T>;
+
+        /// Map post identifier to deposit currently reserved for the post.
+        pub PostDepositById get(post_deposit_by_id): map PostId => BalanceOf<T>;
+
+        /// Map category identifier to threads pinned in the category, in display order.
+        pub PinnedThreadsByCategory get(pinned_threads_by_category): map CategoryId => Vec<ThreadId>;
+
+        /// Greatest number of posts, including initial posts of threads, an account
+        /// can add within `PostRateLimitPeriod` blocks. Zero disables the limit.
+        pub MaxPostsPerRateLimitPeriod get(max_posts_per_rate_limit_period) config(): u32;
+
+        /// Length in blocks of the period over which posts of an account are rate limited.
+        pub PostRateLimitPeriod get(post_rate_limit_period) config(): T::BlockNumber;
+
+        /// Least number of blocks between two threads created by the same account. Zero disables the limit.
+        pub MinBlocksBetweenThreads get(min_blocks_between_threads) config(): T::BlockNumber;
+
+        /// Map account to blocks in which the account added posts within the current rate limit period.
+        /// Only maintained while post rate limit is enabled.
+        pub RecentPostBlocksByAccount get(recent_post_blocks_by_account): map T::AccountId => Vec<T::BlockNumber>;
+
+        /// Map account to block in which the account last created a thread.
+        /// Only maintained while thread rate limit is enabled.
+        pub LastThreadBlockByAccount get(last_thread_block_by_account): map T::AccountId => Option<T::BlockNumber>;
+
+        /// Map account to sanction imposed on it, if any. Expired sanctions may linger until lifted or replaced.
+        pub SanctionByAccount get(sanction_by_account): map T::AccountId => Option<Sanction<T::BlockNumber, T::AccountId>>;
+
+        /// Accounts with a sanction in `SanctionByAccount`, whether or not it has expired.
+        pub SanctionedAccounts get(is_sanctioned_account): linked_map T::AccountId => bool;
+
+        /// Map post identifier to reports of the post, at most `MAX_REPORTS_PER_POST` of them,
+        /// and at most one per reporting account.
+        pub PostReports get(post_reports): map PostId => Vec<PostReport<T::BlockNumber, T::Moment, T::AccountId>>;
+
+        /// Constraint on text of a post report.
+        pub PostReportTextConstraint get(post_report_text_constraint) config(): InputValidationLengthConstraint;
+
+        /// Number of reports after which a post is flagged for moderation,
+        /// at most `MAX_REPORTS_PER_POST`. A threshold of zero disables flagging.
+        pub PostReportThreshold get(post_report_threshold) config(): u32;
+
+        /// Map post identifier to whether the post has reached the report threshold,
+        /// and has not since been moderated or deleted. Only flagged posts have an entry,
+        /// so the map can be enumerated to list them.
+        pub FlaggedPosts get(is_flagged_post): linked_map PostId => bool;
+
+        /// Map appeal identifier to corresponding appeal.
+        pub AppealById get(appeal_by_id) config(): map AppealId => Appeal<T::BlockNumber, T::Moment, T::AccountId>;
+
+        /// Appeal identifier value to be used for next appeal filed.
+        pub NextAppealId get(next_appeal_id) config(): AppealId;
+
+        /// Map moderated thread or post to the most recent appeal filed against its moderation, if any.
+        pub AppealIdBySubject get(appeal_id_by_subject): map AppealSubject => Option<AppealId>;
+
+        /// Map account to whether account may resolve appeals.
+        pub AppealResolvers get(is_appeal_resolver): map T::AccountId => bool;
+
+        /// Constraint on appeal text, and on rationale for resolving an appeal.
+        pub AppealTextConstraint get(appeal_text_constraint) config(): InputValidationLengthConstraint;
+
+        /// Number of blocks after a moderation during which the moderation can be appealed.
+        pub AppealWindow get(appeal_window) config(): T::BlockNumber;
+
+        /// Map thread identifier and account to the index of the poll alternative the account voted for, if any.
+        pub PollVoteByAccount get(poll_vote_by_account): double_map ThreadId, blake2_256(T::AccountId) => Option<u32>;
+    }
+    add_extra_genesis {
+        // Build indices of categories, threads and posts given in genesis.
+        build(|config: &GenesisConfig<T>| {
+            for (category_id, category) in &config.category_by_id {
+                <Module<T>>::index_category_position(*category_id, &category.position_in_parent_category);
+            }
+
+            for (thread_id, thread) in &config.thread_by_id {
+                ThreadIdByCategoryAndNr::insert(thread.category_id, thread.nr_in_category, *thread_id);
+
+                if thread.deleted_at.is_none() {
+                    <ThreadIdByAuthorAndNr<T>>::insert(&thread.author_id, thread.nr_by_author, *thread_id);
+                }
+
+                <NumThreadsCreatedByAuthor<T>>::mutate(&thread.author_id, |n| {
+                    *n = (*n).max(thread.nr_by_author);
+                });
+            }
+
+            for (post_id, post) in &config.post_by_id {
+                PostIdByThreadAndNr::insert(post.thread_id, post.nr_in_thread, *post_id);
+
+                if post.deleted_at.is_none() {
+                    <PostIdByAuthorAndNr<T>>::insert(&post.author_id, post.nr_by_author, *post_id);
+                }
+
+                <NumPostsCreatedByAuthor<T>>::mutate(&post.author_id, |n| {
+                    *n = (*n).max(post.nr_by_author);
+                });
+            }
+        });
     }
     /*
     JUST GIVING UP ON ALL THIS FOR NOW BECAUSE ITS TAKING TOO LONG
@@ -584,37 +1201,143 @@ decl_storage! {
     */
 }
 
+// Hashes of titles, texts and rationales in events are `T::Hashing::hash` of their raw bytes,
+// exactly as stored in state, so clients can check them against state without re-encoding.
 decl_event!(
     pub enum Event<T>
     where
         <T as system::Trait>::AccountId,
+        <T as system::Trait>::BlockNumber,
+        <T as system::Trait>::Hash,
     {
-        /// A category was introduced
-        CategoryCreated(CategoryId),
+        /// A category was introduced by given account, under given parent,
+        /// where `None` means the category is a root category.
+        CategoryCreated(CategoryId, Option<CategoryId>, AccountId),
+
+        /// A category with given id was updated by given account.
+        /// The third argument reflects the new archival status of the category, if changed.
+        /// The fourth argument reflects the new deletion status of the category, if changed.
+        CategoryUpdated(CategoryId, AccountId, Option<bool>, Option<bool>),
+
+        /// A category with given id was moved, along with its subcategories.
+        /// The second argument is the previous parent, and the third argument
+        /// is the new parent, where `None` means the category is a root category.
+        CategoryMoved(CategoryId, Option<CategoryId>, Option<CategoryId>),
 
-        /// A category with given id was updated.
-        /// The second argument reflects the new archival status of the category, if changed.
-        /// The third argument reflects the new deletion status of the category, if changed.
-        CategoryUpdated(CategoryId, Option<bool>, Option<bool>),
+        /// A category with given id had its title and/or description updated by given account,
+        /// to a title and a description having given hashes.
+        /// The last argument reflects the number of past texts kept after the update.
+        CategoryTextUpdated(CategoryId, AccountId, Hash, Hash, u64),
 
-        /// A thread with given id was created.
-        ThreadCreated(ThreadId),
+        /// Posting policy of category with given id was set.
+        CategoryPostingPolicyUpdated(CategoryId, PostingPolicy),
 
-        /// A thread with given id was moderated.
-        ThreadModerated(ThreadId),
+        /// Given account was added to or removed from posting allowlist of category with given id.
+        CategoryPostingAllowlistUpdated(CategoryId, AccountId, bool),
 
-        /// Post with given id was created.
-        PostAdded(PostId),
+        /// A thread with given id was created by given account in given category,
+        /// with title having given hash.
+        ThreadCreated(ThreadId, AccountId, CategoryId, Hash),
 
-        /// Post with givne id was moderated.
-        PostModerated(PostId),
+        /// A thread with given id in given category was moderated by given account,
+        /// with rationale having given hash.
+        ThreadModerated(ThreadId, AccountId, CategoryId, Hash),
 
-        /// Post with given id had its text updated.
-        /// The second argument reflects the number of total edits when the text update occurs.
-        PostTextUpdated(PostId, u64),
+        /// Moderation of thread with given id in given category was reversed by given account,
+        /// with rationale having given hash.
+        ThreadUnmoderated(ThreadId, AccountId, CategoryId, Hash),
+
+        /// Thread with given id in given category had its title updated by given account,
+        /// to a title having given hash.
+        /// The last argument reflects the number of past titles kept after the update.
+        ThreadTitleUpdated(ThreadId, AccountId, CategoryId, Hash, u64),
+
+        /// Post with given id was created by given account in given thread, with text having given hash.
+        /// Also generated for the initial post of a new thread.
+        PostAdded(PostId, AccountId, ThreadId, Hash),
+
+        /// Post with givne id in given thread was moderated by given account,
+        /// with rationale having given hash.
+        PostModerated(PostId, AccountId, ThreadId, Hash),
+
+        /// Moderation of post with given id in given thread was reversed by given account,
+        /// with rationale having given hash.
+        PostUnmoderated(PostId, AccountId, ThreadId, Hash),
+
+        /// Appeal with given id was filed against moderation of given subject.
+        AppealFiled(AppealId, AppealSubject),
+
+        /// Appeal with given id was resolved with given outcome.
+        AppealResolved(AppealId, AppealOutcome),
+
+        /// Given account was added to or removed from appeal resolvers.
+        AppealResolverUpdated(AccountId, bool),
+
+        /// Post with given id was reported by given account for given reason.
+        PostReported(AccountId, PostId, PostReportReason),
+
+        /// Post with given id was flagged for moderation, having reached the report threshold.
+        PostFlagged(PostId),
+
+        /// Post report threshold was updated.
+        /// The first argument is the old value, and the second argument is the new value.
+        PostReportThresholdSet(u32, u32),
+
+        /// Given account was sanctioned, possibly until given block.
+        AccountSanctioned(AccountId, SanctionKind, Option<BlockNumber>),
+
+        /// Sanction of given account was lifted.
+        SanctionLifted(AccountId),
+
+        /// Post with given id in given thread had its text updated by given account,
+        /// to a text having given hash.
+        /// The last argument reflects the number of total edits when the text update occurs.
+        PostTextUpdated(PostId, AccountId, ThreadId, Hash, u64),
+
+        /// Post with given id in given thread was deleted by its author, given account.
+        PostDeletedByAuthor(PostId, AccountId, ThreadId),
+
+        /// Thread with given id in given category was deleted by its author, given account.
+        ThreadDeletedByAuthor(ThreadId, AccountId, CategoryId),
+
+        /// Given account reacted to post with given id in given thread.
+        /// The fourth argument is the new reaction, or `None` if reaction was withdrawn.
+        PostReacted(AccountId, PostId, ThreadId, Option<PostReaction>),
+
+        /// Thread with given id was moved by given account.
+        /// The third argument is the category it was moved from,
+        /// and the fourth argument is the category it was moved to.
+        ThreadMoved(ThreadId, AccountId, CategoryId, CategoryId),
+
+        /// Thread with given id was pinned in its category by given account.
+        ThreadPinned(ThreadId, AccountId),
+
+        /// Thread with given id was unpinned from its category by given account.
+        ThreadUnpinned(ThreadId, AccountId),
+
+        /// Thread with given id was locked by given account.
+        ThreadLocked(ThreadId, AccountId),
+
+        /// Thread with given id was unlocked by given account.
+        ThreadUnlocked(ThreadId, AccountId),
+
+        /// Pinned threads of category with given id were reordered by given account.
+        PinnedThreadsReordered(CategoryId, AccountId),
+
+        /// Given account voted on poll of thread with given id.
+        /// The third argument is the index of the alternative voted for.
+        PollVoted(AccountId, ThreadId, u32),
 
         /// Given account was set as forum sudo.
         ForumSudoSet(Option<AccountId>, Option<AccountId>),
+
+        /// Maximum category depth was updated.
+        /// The first argument is the old value, and the second argument is the new value.
+        MaxCategoryDepthSet(u16, u16),
+
+        /// Moderator status of given account in category with given id was updated.
+        /// The third argument reflects whether account is now a moderator.
+        CategoryModeratorUpdated(CategoryId, AccountId, bool),
     }
 );
 
@@ -648,8 +1371,30 @@ decl_module! {
             Ok(())
         }
 
-        /// Add a new category.
-        fn create_category(origin, parent: Option<CategoryId>, title: Vec<u8>, description: Vec<u8>) -> dispatch::Result {
+        /// Set maximum category depth, which cannot be lower than the depth of any existing category.
+        fn set_max_category_depth(origin, new_max_category_depth: u16) -> dispatch::Result {
+            ensure_root(origin)?;
+
+            // Make sure no existing category becomes too deep
+            ensure!(
+                Self::greatest_category_depth() <= new_max_category_depth as usize,
+                ERROR_EXISTING_CATEGORY_EXCEEDS_MAX_CATEGORY_DEPTH
+            );
+
+            // Hold on to old value
+            let old_max_category_depth = MaxCategoryDepth::get();
+
+            // Update max category depth
+            MaxCategoryDepth::put(new_max_category_depth);
+
+            // Generate event
+            Self::deposit_event(RawEvent::MaxCategoryDepthSet(old_max_category_depth, new_max_category_depth));
+
+            Ok(())
+        }
+
+        /// Add or remove account as moderator of category, and hence of all its subcategories.
+        fn set_category_moderator(origin, category_id: CategoryId, account_id: T::AccountId, is_moderator: bool) -> dispatch::Result {
 
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
@@ -657,28 +1402,74 @@ decl_module! {
             // Not signed by forum SUDO
             Self::ensure_is_forum_sudo(&who)?;
 
-            // Validate title
-            Self::ensure_category_title_is_valid(&title)?;
+            // Make sure category exists
+            ensure!(
+                <CategoryById<T>>::exists(&category_id),
+                ERROR_CATEGORY_DOES_NOT_EXIST
+            );
 
-            // Validate description
-            Self::ensure_category_description_is_valid(&description)?;
+            /*
+             * Here we are safe to mutate
+             */
 
-            // Position in parent field value for new category
-            let mut position_in_parent_category_field = None;
+            let was_moderator = <CategoryModerators<T>>::get(category_id, &account_id);
 
-            // If not root, then check that we can create in parent category
-            if let Some(parent_category_id) = parent {
+            if is_moderator && !was_moderator {
+                <CategoryModerators<T>>::insert(category_id, &account_id, true);
 
-                let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(parent_category_id)?;
+                <NumModeratedCategoriesByAccount<T>>::mutate(&account_id, |n| {
+                    *n += 1;
+                });
+            } else if !is_moderator && was_moderator {
+                <CategoryModerators<T>>::remove(category_id, &account_id);
 
-                // Can we mutate in this category?
-                Self::ensure_can_add_subcategory_path_leaf(&category_tree_path)?;
+                <NumModeratedCategoriesByAccount<T>>::mutate(&account_id, |n| {
+                    *n -= 1;
+                });
+            }
 
-                /*
-                 * Here we are safe to mutate
-                 */
+            // Generate event
+            Self::deposit_event(RawEvent::CategoryModeratorUpdated(category_id, account_id, is_moderator));
 
-                // Increment number of subcategories to reflect this new category being
+            Ok(())
+        }
+
+        /// Add a new category.
+        fn create_category(origin, parent: Option<CategoryId>, title: Vec<u8>, description: Vec<u8>) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Only forum SUDO can create root categories
+            if parent.is_none() {
+                Self::ensure_is_forum_sudo(&who)?;
+            }
+
+            // Validate title
+            Self::ensure_category_title_is_valid(&title)?;
+
+            // Validate description
+            Self::ensure_category_description_is_valid(&description)?;
+
+            // Position in parent field value for new category
+            let mut position_in_parent_category_field = None;
+
+            // If not root, then check that we can create in parent category
+            if let Some(parent_category_id) = parent {
+
+                let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(parent_category_id)?;
+
+                // Signed by forum SUDO or moderator of parent category
+                Self::ensure_is_forum_sudo_or_category_moderator(&who, &category_tree_path)?;
+
+                // Can we mutate in this category?
+                Self::ensure_can_add_subcategory_path_leaf(&category_tree_path)?;
+
+                /*
+                 * Here we are safe to mutate
+                 */
+
+                // Increment number of subcategories to reflect this new category being
                 // added as a child
                 <CategoryById<T>>::mutate(parent_category_id, |c| {
                     c.num_direct_subcategories += 1;
@@ -689,7 +1480,7 @@ decl_module! {
 
                 position_in_parent_category_field = Some(ChildPositionInParentCategory{
                     parent_id: parent_category_id,
-                    child_nr_in_parent_category: parent_category.num_direct_subcategories
+                    child_nr_in_parent_category: parent_category.num_subcategories_created() + 1
                 });
 
             }
@@ -711,18 +1502,25 @@ decl_module! {
                 num_direct_subcategories: 0,
                 num_direct_unmoderated_threads: 0,
                 num_direct_moderated_threads: 0,
+                num_direct_deleted_threads: 0,
+                num_direct_moved_threads: 0,
+                num_direct_moved_subcategories: 0,
                 position_in_parent_category: position_in_parent_category_field,
-                moderator_id: who
+                moderator_id: who.clone(),
+                text_change_history: vec![]
             };
 
             // Insert category in map
-            <CategoryById<T>>::insert(new_category.id, new_category);
+            <CategoryById<T>>::insert(new_category.id, new_category.clone());
+
+            // Add category to child index of its parent, or to root categories
+            Self::index_category_position(new_category.id, &new_category.position_in_parent_category);
 
             // Update other things
             NextCategoryId::put(next_category_id + 1);
 
             // Generate event
-            Self::deposit_event(RawEvent::CategoryCreated(next_category_id));
+            Self::deposit_event(RawEvent::CategoryCreated(next_category_id, parent, who));
 
             Ok(())
         }
@@ -733,9 +1531,6 @@ decl_module! {
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Not signed by forum SUDO
-            Self::ensure_is_forum_sudo(&who)?;
-
             // Make sure something is actually being changed
             ensure!(
                 new_archival_status.is_some() || new_deletion_status.is_some(),
@@ -745,6 +1540,9 @@ decl_module! {
             // Get path from parent to root of category tree.
             let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(category_id)?;
 
+            // Signed by forum SUDO or moderator of category
+            Self::ensure_is_forum_sudo_or_category_moderator(&who, &category_tree_path)?;
+
             // When we are dealing with a non-root category, we
             // must ensure mutability of our category by traversing to
             // root.
@@ -785,13 +1583,230 @@ decl_module! {
             });
 
             // Generate event
-            Self::deposit_event(RawEvent::CategoryUpdated(category_id, new_archival_status, new_deletion_status));
+            Self::deposit_event(RawEvent::CategoryUpdated(category_id, who, new_archival_status, new_deletion_status));
+
+            Ok(())
+        }
+
+        /// Move category, along with its subcategories, under a new parent category,
+        /// or make it a root category if no new parent is given.
+        fn move_category(origin, category_id: CategoryId, new_parent: Option<CategoryId>) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Not signed by forum SUDO
+            Self::ensure_is_forum_sudo(&who)?;
+
+            // Get category
+            let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(category_id)?;
+
+            let category = category_tree_path.first().unwrap();
+
+            let old_position_in_parent_category = category.position_in_parent_category.clone();
+
+            let old_parent = old_position_in_parent_category
+                .as_ref()
+                .map(|position| position.parent_id);
+
+            // Make sure something is actually being changed
+            ensure!(old_parent != new_parent, ERROR_CATEGORY_ALREADY_IN_PARENT);
+
+            // Depth of category after being moved
+            let mut new_depth = 0;
+
+            if let Some(new_parent_id) = new_parent {
+
+                let new_parent_tree_path = Self::ensure_valid_category_and_build_category_tree_path(new_parent_id)?;
+
+                // Category cannot become its own ancestor
+                ensure!(
+                    !new_parent_tree_path.iter().any(|c| c.id == category_id),
+                    ERROR_CATEGORY_CANNOT_BE_MOVED_INTO_ITS_SUBTREE
+                );
+
+                // Can we mutate in new parent category?
+                Self::ensure_can_mutate_in_path_leaf(&new_parent_tree_path)?;
+
+                new_depth = new_parent_tree_path.len();
+            }
+
+            // Deepest category in the moved subtree must not exceed maximum depth
+            ensure!(
+                new_depth + Self::category_subtree_height(category_id) <= MaxCategoryDepth::get() as usize,
+                ERROR_MAX_VALID_CATEGORY_DEPTH_EXCEEDED
+            );
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            // Remove category from old parent
+            if let Some(old_parent_id) = old_parent {
+                <CategoryById<T>>::mutate(old_parent_id, |c| {
+                    c.num_direct_subcategories -= 1;
+                    c.num_direct_moved_subcategories += 1;
+                });
+            }
+
+            // Add category to new parent
+            let new_position_in_parent_category = new_parent.map(|new_parent_id| {
+                let new_parent_category = <CategoryById<T>>::get(new_parent_id);
+
+                <CategoryById<T>>::mutate(new_parent_id, |c| {
+                    c.num_direct_subcategories += 1;
+                });
+
+                ChildPositionInParentCategory {
+                    parent_id: new_parent_id,
+                    child_nr_in_parent_category: new_parent_category.num_subcategories_created() + 1
+                }
+            });
+
+            // Update category index
+            Self::unindex_category_position(category_id, &old_position_in_parent_category);
+
+            Self::index_category_position(category_id, &new_position_in_parent_category);
+
+            <CategoryById<T>>::mutate(category_id, |c| {
+                c.position_in_parent_category = new_position_in_parent_category;
+            });
+
+            // Generate event
+            Self::deposit_event(RawEvent::CategoryMoved(category_id, old_parent, new_parent));
+
+            Ok(())
+        }
+
+        /// Edit category title and/or description
+        fn edit_category_text(origin, category_id: CategoryId, new_title: Option<Vec<u8>>, new_description: Option<Vec<u8>>) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Make sure something is actually being changed
+            ensure!(
+                new_title.is_some() || new_description.is_some(),
+                ERROR_CATEGORY_NOT_BEING_UPDATED
+            );
+
+            // Validate title
+            if let Some(ref title) = new_title {
+                Self::ensure_category_title_is_valid(title)?;
+            }
+
+            // Validate description
+            if let Some(ref description) = new_description {
+                Self::ensure_category_description_is_valid(description)?;
+            }
+
+            // Get path from category to root of category tree.
+            let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(category_id)?;
+
+            // Signed by forum SUDO or moderator of category
+            Self::ensure_is_forum_sudo_or_category_moderator(&who, &category_tree_path)?;
+
+            // Neither category nor any ancestor is deleted or archived
+            Self::ensure_can_mutate_in_path_leaf(&category_tree_path)?;
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            let category = category_tree_path.first().unwrap();
+
+            let new_title_hash = T::Hashing::hash(new_title.as_ref().unwrap_or(&category.title));
+
+            let new_description_hash = T::Hashing::hash(new_description.as_ref().unwrap_or(&category.description));
+
+            let text_change_history_length =
+                (category.text_change_history.len() + 1).min(MAX_CATEGORY_TEXT_CHANGE_HISTORY_LENGTH) as u64;
+
+            <CategoryById<T>>::mutate(category_id, |c| {
+
+                let expired_category_text = CategoryTextChange {
+                    expired_at: Self::current_block_and_time(),
+                    title: category.title.clone(),
+                    description: category.description.clone()
+                };
+
+                if let Some(title) = new_title {
+                    c.title = title;
+                }
+
+                if let Some(description) = new_description {
+                    c.description = description;
+                }
+
+                // Drop oldest text when history is full
+                if c.text_change_history.len() >= MAX_CATEGORY_TEXT_CHANGE_HISTORY_LENGTH {
+                    c.text_change_history.remove(0);
+                }
+
+                // Copy current text to history of expired texts
+                c.text_change_history.push(expired_category_text);
+            });
+
+            // Generate event
+            Self::deposit_event(RawEvent::CategoryTextUpdated(category_id, who, new_title_hash, new_description_hash, text_change_history_length));
+
+            Ok(())
+        }
+
+        /// Set posting policy of category
+        fn set_category_posting_policy(origin, category_id: CategoryId, policy: PostingPolicy) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Get path from category to root of category tree.
+            let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(category_id)?;
+
+            // Signed by forum SUDO or moderator of category
+            Self::ensure_is_forum_sudo_or_category_moderator(&who, &category_tree_path)?;
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            PostingPolicyByCategory::insert(category_id, policy);
+
+            // Generate event
+            Self::deposit_event(RawEvent::CategoryPostingPolicyUpdated(category_id, policy));
+
+            Ok(())
+        }
+
+        /// Add or remove account from posting allowlist of category
+        fn set_category_posting_allowlist(origin, category_id: CategoryId, account_id: T::AccountId, is_allowed: bool) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Get path from category to root of category tree.
+            let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(category_id)?;
+
+            // Signed by forum SUDO or moderator of category
+            Self::ensure_is_forum_sudo_or_category_moderator(&who, &category_tree_path)?;
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            if is_allowed {
+                <CategoryPostingAllowlist<T>>::insert(category_id, &account_id, true);
+            } else {
+                <CategoryPostingAllowlist<T>>::remove(category_id, &account_id);
+            }
+
+            // Generate event
+            Self::deposit_event(RawEvent::CategoryPostingAllowlistUpdated(category_id, account_id, is_allowed));
 
             Ok(())
         }
 
-        /// Create new thread in category
-        fn create_thread(origin, category_id: CategoryId, title: Vec<u8>, text: Vec<u8>) -> dispatch::Result {
+        /// Create new thread in category, with an optional poll attached
+        fn create_thread(origin, category_id: CategoryId, title: Vec<u8>, text: Vec<u8>, poll: Option<PollInput<T::BlockNumber>>) -> dispatch::Result {
 
             /*
              * Update SPEC with new errors,
@@ -811,24 +1826,55 @@ decl_module! {
             // No ancestor is blocking us doing mutation in this category
             Self::ensure_can_mutate_in_path_leaf(&category_tree_path)?;
 
+            // Posting policy of category allows account to create threads
+            Self::ensure_posting_policy_allows_thread(&who, category_id, &category_tree_path)?;
+
             // Validate title
             Self::ensure_thread_title_is_valid(&title)?;
 
             // Validate post text
             Self::ensure_post_text_is_valid(&text)?;
 
+            // Validate poll
+            if let Some(ref poll) = poll {
+                Self::ensure_poll_is_valid(poll)?;
+            }
+
+            // Account is not creating threads or posts too frequently
+            Self::ensure_thread_rate_limit_not_exceeded(&who)?;
+
+            Self::ensure_post_rate_limit_not_exceeded(&who)?;
+
             /*
              * Here it is safe to mutate state.
              */
 
+            // Reserve deposits for thread and its initial post
+            let thread_deposit = <ThreadDeposit<T>>::get();
+            let post_deposit = <PostDeposit<T>>::get();
+
+            T::Currency::reserve(&who, thread_deposit.saturating_add(post_deposit))
+                .map_err(|_| ERROR_INSUFFICIENT_BALANCE_FOR_DEPOSIT)?;
+
             // Add thread
-            let thread = Self::add_new_thread(category_id, &title, &who);
+            let thread = Self::add_new_thread(category_id, &title, &who, poll);
+
+            <ThreadDepositById<T>>::insert(thread.id, thread_deposit);
 
             // Add inital post to thread
-            Self::add_new_post(thread.id, &text, &who);
+            let post = Self::add_new_post(thread.id, &text, &who, None);
 
-            // Generate event
-            Self::deposit_event(RawEvent::ThreadCreated(thread.id));
+            <PostDepositById<T>>::insert(post.id, post_deposit);
+
+            // Update rate limit tracking of account
+            Self::record_thread_for_rate_limit(&who);
+
+            Self::record_post_for_rate_limit(&who);
+
+            // Generate events
+            Self::deposit_event(RawEvent::ThreadCreated(thread.id, who.clone(), category_id, T::Hashing::hash(&title)));
+
+            Self::deposit_event(RawEvent::PostAdded(post.id, who, thread.id, T::Hashing::hash(&text)));
 
             Ok(())
         }
@@ -839,24 +1885,27 @@ decl_module! {
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Signed by forum SUDO
-            Self::ensure_is_forum_sudo(&who)?;
-
             // Get thread
             let mut thread = Self::ensure_thread_exists(&thread_id)?;
 
-            // Thread is not already moderated
-            ensure!(thread.moderation.is_none(), ERROR_THREAD_ALREADY_MODERATED);
-
-            // Rationale valid
-            Self::ensure_thread_moderation_rationale_is_valid(&rationale)?;
-
             // Can mutate in corresponding category
             let path = Self::build_category_tree_path(thread.category_id);
 
             // Path must be non-empty, as category id is from thread in state
             assert!(!path.is_empty());
 
+            // Signed by forum SUDO or moderator of category
+            Self::ensure_is_forum_sudo_or_category_moderator(&who, &path)?;
+
+            // Thread is not already moderated
+            ensure!(thread.moderation.is_none(), ERROR_THREAD_ALREADY_MODERATED);
+
+            // Thread is not deleted
+            ensure!(thread.deleted_at.is_none(), ERROR_THREAD_DELETED);
+
+            // Rationale valid
+            Self::ensure_thread_moderation_rationale_is_valid(&rationale)?;
+
             Self::ensure_can_mutate_in_path_leaf(&path)?;
 
             /*
@@ -866,7 +1915,7 @@ decl_module! {
             // Add moderation to thread
             thread.moderation = Some(ModerationAction {
                 moderated_at: Self::current_block_and_time(),
-                moderator_id: who,
+                moderator_id: who.clone(),
                 rationale: rationale.clone()
             });
 
@@ -878,82 +1927,424 @@ decl_module! {
                 category.num_direct_moderated_threads += 1;
             });
 
+            // Slash deposit of thread
+            Self::slash_deposit(&thread.author_id, <ThreadDepositById<T>>::take(thread_id));
+
+            // Posts can no longer be deleted or moderated, and posts of thread author are slashed
+            Self::release_posts_of_thread(&thread, true);
+
+            // Moderated threads are not pinned
+            Self::unpin_thread_from_category(thread.category_id, thread_id);
+
             // Generate event
-            Self::deposit_event(RawEvent::ThreadModerated(thread_id));
+            Self::deposit_event(RawEvent::ThreadModerated(thread_id, who, thread.category_id, T::Hashing::hash(&rationale)));
 
             Ok(())
         }
 
-        /// Edit post text
-        fn add_post(origin, thread_id: ThreadId, text: Vec<u8>) -> dispatch::Result {
-
-            /*
-             * Update SPEC with new errors,
-             */
+        /// Reverse moderation of thread, keeping original moderation in history of thread.
+        /// Slashed part of thread deposit is not restored.
+        fn unmoderate_thread(origin, thread_id: ThreadId, rationale: Vec<u8>) -> dispatch::Result {
 
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Check that account is forum member
-            Self::ensure_is_forum_member(&who)?;
+            // Get thread
+            let thread = Self::ensure_thread_exists(&thread_id)?;
 
-            // Validate post text
-            Self::ensure_post_text_is_valid(&text)?;
+            // Thread is moderated
+            ensure!(thread.moderation.is_some(), ERROR_THREAD_NOT_MODERATED);
 
-            // Make sure thread exists and is mutable
-            let thread = Self::ensure_thread_is_mutable(&thread_id)?;
+            // Get path from category of thread to root of category tree.
+            let path = Self::build_category_tree_path(thread.category_id);
 
-            // Get path from parent to root of category tree.
-            let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(thread.category_id)?;
+            // Signed by forum SUDO or moderator of category
+            Self::ensure_is_forum_sudo_or_category_moderator(&who, &path)?;
+
+            // Rationale valid
+            Self::ensure_thread_moderation_rationale_is_valid(&rationale)?;
 
             // No ancestor is blocking us doing mutation in this category
-            Self::ensure_can_mutate_in_path_leaf(&category_tree_path)?;
+            Self::ensure_can_mutate_in_path_leaf(&path)?;
 
             /*
              * Here we are safe to mutate
              */
 
-            let post = Self::add_new_post(thread_id, &text, &who);
-
-            // Generate event
-            Self::deposit_event(RawEvent::PostAdded(post.id));
+            Self::reverse_thread_moderation(thread, ModerationAction {
+                moderated_at: Self::current_block_and_time(),
+                moderator_id: who,
+                rationale
+            });
 
             Ok(())
         }
 
-        /// Edit post text
-        fn edit_post_text(origin, post_id: PostId, new_text: Vec<u8>) -> dispatch::Result {
-
-            /* Edit spec.
-              - forum member guard missing
-              - check that both post and thread and category are mutable
-            */
+        /// Edit thread title, either as author of thread or as moderator
+        fn edit_thread_title(origin, thread_id: ThreadId, new_title: Vec<u8>) -> dispatch::Result {
 
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Check that account is forum member
-            Self::ensure_is_forum_member(&who)?;
+            // Validate title
+            Self::ensure_thread_title_is_valid(&new_title)?;
 
-            // Validate post text
-            Self::ensure_post_text_is_valid(&new_text)?;
+            // Make sure thread exists and is mutable
+            let thread = Self::ensure_thread_is_mutable(&thread_id)?;
 
-            // Make sure there exists a mutable post with post id `post_id`
-            let post = Self::ensure_post_is_mutable(&post_id)?;
+            // Signed by author of thread, forum SUDO or moderator of category
+            let category_tree_path = Self::build_category_tree_path(thread.category_id);
 
-            // Signer does not match creator of post with identifier postId
-            ensure!(post.author_id == who, ERROR_ACCOUNT_DOES_NOT_MATCH_POST_AUTHOR);
+            let is_moderator = Self::is_forum_sudo_or_category_moderator(&who, &category_tree_path);
+
+            ensure!(
+                thread.author_id == who || is_moderator,
+                ERROR_ORIGIN_NOT_THREAD_AUTHOR_OR_MODERATOR
+            );
+
+            // Author without moderation rights must be a forum member
+            if !is_moderator {
+                Self::ensure_is_forum_member(&who)?;
+            }
 
             /*
              * Here we are safe to mutate
              */
 
-            <PostById<T>>::mutate(post_id, |p| {
+            let expired_thread_title = ThreadTitleChange {
+                expired_at: Self::current_block_and_time(),
+                title: thread.title.clone()
+            };
 
-                let expired_post_text = PostTextChange {
-                    expired_at: Self::current_block_and_time(),
-                    text: post.current_text.clone()
-                };
+            let mut title_change_history = thread.title_change_history;
+
+            // Drop oldest title when history is full
+            if title_change_history.len() >= MAX_THREAD_TITLE_CHANGE_HISTORY_LENGTH {
+                title_change_history.remove(0);
+            }
+
+            title_change_history.push(expired_thread_title);
+
+            let title_change_history_length = title_change_history.len() as u64;
+
+            let new_title_hash = T::Hashing::hash(&new_title);
+
+            <ThreadById<T>>::mutate(thread_id, |t| {
+                t.title = new_title;
+                t.title_change_history = title_change_history;
+            });
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadTitleUpdated(thread_id, who, thread.category_id, new_title_hash, title_change_history_length));
+
+            Ok(())
+        }
+
+        /// Lock thread, preventing posts from being added or edited, without moderating it
+        fn lock_thread(origin, thread_id: ThreadId) -> dispatch::Result {
+            Self::set_thread_lock(origin, thread_id, true)
+        }
+
+        /// Unlock thread previously locked
+        fn unlock_thread(origin, thread_id: ThreadId) -> dispatch::Result {
+            Self::set_thread_lock(origin, thread_id, false)
+        }
+
+        /// Pin thread in its category, after any already pinned threads
+        fn pin_thread(origin, thread_id: ThreadId) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Make sure thread exists and is mutable
+            let thread = Self::ensure_thread_is_mutable(&thread_id)?;
+
+            // Get path from category of thread to root of category tree.
+            let category_tree_path = Self::build_category_tree_path(thread.category_id);
+
+            // Signed by forum SUDO or moderator of category
+            Self::ensure_is_forum_sudo_or_category_moderator(&who, &category_tree_path)?;
+
+            // No ancestor is blocking us doing mutation in this category
+            Self::ensure_can_mutate_in_path_leaf(&category_tree_path)?;
+
+            // Make sure thread can be pinned
+            let pinned_threads = PinnedThreadsByCategory::get(thread.category_id);
+
+            ensure!(!pinned_threads.contains(&thread_id), ERROR_THREAD_ALREADY_PINNED);
+
+            ensure!(
+                pinned_threads.len() < MAX_PINNED_THREADS_PER_CATEGORY,
+                ERROR_MAX_PINNED_THREADS_EXCEEDED
+            );
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            PinnedThreadsByCategory::mutate(thread.category_id, |pinned_threads| {
+                pinned_threads.push(thread_id);
+            });
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadPinned(thread_id, who));
+
+            Ok(())
+        }
+
+        /// Unpin thread from its category
+        fn unpin_thread(origin, thread_id: ThreadId) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Get thread
+            let thread = Self::ensure_thread_exists(&thread_id)?;
+
+            // Get path from category of thread to root of category tree.
+            let category_tree_path = Self::build_category_tree_path(thread.category_id);
+
+            // Signed by forum SUDO or moderator of category
+            Self::ensure_is_forum_sudo_or_category_moderator(&who, &category_tree_path)?;
+
+            // No ancestor is blocking us doing mutation in this category
+            Self::ensure_can_mutate_in_path_leaf(&category_tree_path)?;
+
+            // Make sure thread is pinned
+            ensure!(
+                PinnedThreadsByCategory::get(thread.category_id).contains(&thread_id),
+                ERROR_THREAD_NOT_PINNED
+            );
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            Self::unpin_thread_from_category(thread.category_id, thread_id);
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadUnpinned(thread_id, who));
+
+            Ok(())
+        }
+
+        /// Reorder pinned threads of category, `new_order` must contain exactly the pinned threads
+        fn reorder_pinned_threads(origin, category_id: CategoryId, new_order: Vec<ThreadId>) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Get path from category to root of category tree.
+            let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(category_id)?;
+
+            // Signed by forum SUDO or moderator of category
+            Self::ensure_is_forum_sudo_or_category_moderator(&who, &category_tree_path)?;
+
+            // No ancestor is blocking us doing mutation in this category
+            Self::ensure_can_mutate_in_path_leaf(&category_tree_path)?;
+
+            // Make sure new order is a permutation of pinned threads
+            let pinned_threads = PinnedThreadsByCategory::get(category_id);
+
+            ensure!(
+                new_order.len() == pinned_threads.len() &&
+                    pinned_threads.iter().all(|thread_id| new_order.contains(thread_id)),
+                ERROR_PINNED_THREADS_ORDER_INVALID
+            );
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            PinnedThreadsByCategory::insert(category_id, new_order);
+
+            // Generate event
+            Self::deposit_event(RawEvent::PinnedThreadsReordered(category_id, who));
+
+            Ok(())
+        }
+
+        /// Move thread to another category, where it is given a new number
+        fn move_thread_to_category(origin, thread_id: ThreadId, new_category_id: CategoryId) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Get thread
+            let thread = Self::ensure_thread_exists(&thread_id)?;
+
+            // Thread is not deleted
+            ensure!(thread.deleted_at.is_none(), ERROR_THREAD_DELETED);
+
+            // Thread is actually being moved
+            ensure!(thread.category_id != new_category_id, ERROR_THREAD_ALREADY_IN_CATEGORY);
+
+            // Signed by forum SUDO or moderator of current category
+            let old_category_tree_path = Self::build_category_tree_path(thread.category_id);
+
+            Self::ensure_is_forum_sudo_or_category_moderator(&who, &old_category_tree_path)?;
+
+            // Get path from new category to root of category tree.
+            let new_category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(new_category_id)?;
+
+            // Signed by forum SUDO or moderator of new category
+            Self::ensure_is_forum_sudo_or_category_moderator(&who, &new_category_tree_path)?;
+
+            // No ancestor is blocking us doing mutation in new category
+            Self::ensure_can_mutate_in_path_leaf(&new_category_tree_path)?;
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            let is_moderated = thread.moderation.is_some();
+
+            // Pinning does not carry over to new category
+            Self::unpin_thread_from_category(thread.category_id, thread_id);
+
+            // Update thread counts of old category
+            <CategoryById<T>>::mutate(thread.category_id, |c| {
+                if is_moderated {
+                    c.num_direct_moderated_threads -= 1;
+                } else {
+                    c.num_direct_unmoderated_threads -= 1;
+                }
+
+                c.num_direct_moved_threads += 1;
+            });
+
+            // Place thread in new category
+            let new_category = new_category_tree_path.first().unwrap();
+
+            let new_nr_in_category = new_category.num_threads_created() + 1;
+
+            <ThreadById<T>>::mutate(thread_id, |t| {
+                t.category_id = new_category_id;
+                t.nr_in_category = new_nr_in_category;
+            });
+
+            // Update thread index of both categories
+            ThreadIdByCategoryAndNr::remove(thread.category_id, thread.nr_in_category);
+
+            ThreadIdByCategoryAndNr::insert(new_category_id, new_nr_in_category, thread_id);
+
+            // Update thread counts of new category
+            <CategoryById<T>>::mutate(new_category_id, |c| {
+                if is_moderated {
+                    c.num_direct_moderated_threads += 1;
+                } else {
+                    c.num_direct_unmoderated_threads += 1;
+                }
+            });
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadMoved(thread_id, who, thread.category_id, new_category_id));
+
+            Ok(())
+        }
+
+        /// Add post to thread, possibly as a reply to another post in the thread
+        fn add_post(origin, thread_id: ThreadId, text: Vec<u8>, reply_to: Option<PostId>) -> dispatch::Result {
+
+            /*
+             * Update SPEC with new errors,
+             */
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Check that account is forum member
+            Self::ensure_is_forum_member(&who)?;
+
+            // Validate post text
+            Self::ensure_post_text_is_valid(&text)?;
+
+            // Make sure thread exists and is mutable
+            let thread = Self::ensure_thread_is_mutable(&thread_id)?;
+
+            // Thread is not locked
+            ensure!(!thread.locked, ERROR_THREAD_LOCKED);
+
+            // Get path from parent to root of category tree.
+            let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(thread.category_id)?;
+
+            // No ancestor is blocking us doing mutation in this category
+            Self::ensure_can_mutate_in_path_leaf(&category_tree_path)?;
+
+            // Posting policy of category allows account to add posts
+            Self::ensure_posting_policy_allows_post(&who, thread.category_id, &category_tree_path)?;
+
+            // Make sure replied post is in the same thread
+            if let Some(replied_post_id) = reply_to {
+                let replied_post = Self::ensure_post_exists(&replied_post_id)?;
+
+                ensure!(replied_post.thread_id == thread_id, ERROR_REPLIED_POST_NOT_IN_THREAD);
+            }
+
+            // Account is not adding posts too frequently
+            Self::ensure_post_rate_limit_not_exceeded(&who)?;
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            // Reserve deposit for post
+            let post_deposit = <PostDeposit<T>>::get();
+
+            T::Currency::reserve(&who, post_deposit)
+                .map_err(|_| ERROR_INSUFFICIENT_BALANCE_FOR_DEPOSIT)?;
+
+            let post = Self::add_new_post(thread_id, &text, &who, reply_to);
+
+            <PostDepositById<T>>::insert(post.id, post_deposit);
+
+            // Update rate limit tracking of account
+            Self::record_post_for_rate_limit(&who);
+
+            // Generate event
+            Self::deposit_event(RawEvent::PostAdded(post.id, who, thread_id, T::Hashing::hash(&text)));
+
+            Ok(())
+        }
+
+        /// Edit post text
+        fn edit_post_text(origin, post_id: PostId, new_text: Vec<u8>) -> dispatch::Result {
+
+            /* Edit spec.
+              - forum member guard missing
+              - check that both post and thread and category are mutable
+            */
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Check that account is forum member
+            Self::ensure_is_forum_member(&who)?;
+
+            // Validate post text
+            Self::ensure_post_text_is_valid(&new_text)?;
+
+            // Make sure there exists a mutable post with post id `post_id`
+            let post = Self::ensure_post_is_mutable(&post_id)?;
+
+            // Signer does not match creator of post with identifier postId
+            ensure!(post.author_id == who, ERROR_ACCOUNT_DOES_NOT_MATCH_POST_AUTHOR);
+
+            // Thread of post is not locked
+            ensure!(!<ThreadById<T>>::get(post.thread_id).locked, ERROR_THREAD_LOCKED);
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            let new_text_hash = T::Hashing::hash(&new_text);
+
+            <PostById<T>>::mutate(post_id, |p| {
+
+                let expired_post_text = PostTextChange {
+                    expired_at: Self::current_block_and_time(),
+                    text: post.current_text.clone()
+                };
 
                 // Set current text to new text
                 p.current_text = new_text;
@@ -963,7 +2354,7 @@ decl_module! {
             });
 
             // Generate event
-            Self::deposit_event(RawEvent::PostTextUpdated(post.id, post.text_change_history.len() as u64));
+            Self::deposit_event(RawEvent::PostTextUpdated(post.id, who, post.thread_id, new_text_hash, post.text_change_history.len() as u64));
 
             Ok(())
         }
@@ -974,12 +2365,16 @@ decl_module! {
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Signed by forum SUDO
-            Self::ensure_is_forum_sudo(&who)?;
-
             // Make sure post exists and is mutable
             let post = Self::ensure_post_is_mutable(&post_id)?;
 
+            // Signed by forum SUDO or moderator of category of thread
+            let thread = <ThreadById<T>>::get(post.thread_id);
+
+            let path = Self::build_category_tree_path(thread.category_id);
+
+            Self::ensure_is_forum_sudo_or_category_moderator(&who, &path)?;
+
             Self::ensure_post_moderation_rationale_is_valid(&rationale)?;
 
             /*
@@ -989,22 +2384,594 @@ decl_module! {
             // Update moderation action on post
             let moderation_action = ModerationAction{
                 moderated_at: Self::current_block_and_time(),
-                moderator_id: who,
+                moderator_id: who.clone(),
                 rationale: rationale.clone()
             };
 
-            <PostById<T>>::mutate(post_id, |p| {
-                p.moderation = Some(moderation_action);
-            });
+            <PostById<T>>::mutate(post_id, |p| {
+                p.moderation = Some(moderation_action);
+            });
+
+            // Update moderated and unmoderated post count of corresponding thread
+            <ThreadById<T>>::mutate(post.thread_id, |t| {
+                t.num_unmoderated_posts -= 1;
+                t.num_moderated_posts += 1;
+            });
+
+            // Slash deposit of post
+            Self::slash_deposit(&post.author_id, <PostDepositById<T>>::take(post_id));
+
+            // Reports of moderated posts are cleared, so they can be reported again if unmoderated
+            Self::clear_post_reports(post_id);
+
+            // Generate event
+            Self::deposit_event(RawEvent::PostModerated(post.id, who, post.thread_id, T::Hashing::hash(&rationale)));
+
+            Ok(())
+        }
+
+        /// Reverse moderation of post, keeping original moderation in history of post.
+        /// Slashed part of post deposit is not restored.
+        fn unmoderate_post(origin, post_id: PostId, rationale: Vec<u8>) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Get post
+            let post = Self::ensure_post_exists(&post_id)?;
+
+            // Post is moderated
+            ensure!(post.moderation.is_some(), ERROR_POST_NOT_MODERATED);
+
+            // Make sure thread of post is mutable
+            let thread = Self::ensure_thread_is_mutable(&post.thread_id)?;
+
+            // Signed by forum SUDO or moderator of category of thread
+            let path = Self::build_category_tree_path(thread.category_id);
+
+            Self::ensure_is_forum_sudo_or_category_moderator(&who, &path)?;
+
+            // Rationale valid
+            Self::ensure_post_moderation_rationale_is_valid(&rationale)?;
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            Self::reverse_post_moderation(post, ModerationAction {
+                moderated_at: Self::current_block_and_time(),
+                moderator_id: who,
+                rationale
+            });
+
+            Ok(())
+        }
+
+        /// Report post to moderators, at most once per account.
+        fn report_post(origin, post_id: PostId, reason: PostReportReason, text: Option<Vec<u8>>) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Check that account is forum member
+            Self::ensure_is_forum_member(&who)?;
+
+            // Validate text, if present
+            if let Some(ref text) = text {
+                Self::ensure_post_report_text_is_valid(text)?;
+            }
+
+            // Make sure post exists and is mutable
+            Self::ensure_post_is_mutable(&post_id)?;
+
+            // Make sure post can be reported by account
+            let reports = <PostReports<T>>::get(post_id);
+
+            ensure!(
+                !reports.iter().any(|report| report.reporter_id == who),
+                ERROR_POST_ALREADY_REPORTED_BY_ACCOUNT
+            );
+
+            ensure!(reports.len() < MAX_REPORTS_PER_POST, ERROR_MAX_POST_REPORTS_EXCEEDED);
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            let num_reports = reports.len() as u32 + 1;
+
+            <PostReports<T>>::mutate(post_id, |reports| {
+                reports.push(PostReport {
+                    reporter_id: who.clone(),
+                    reason,
+                    text,
+                    reported_at: Self::current_block_and_time()
+                });
+            });
+
+            // Generate event
+            Self::deposit_event(RawEvent::PostReported(who, post_id, reason));
+
+            // Flag post when report threshold is reached
+            if num_reports == PostReportThreshold::get() {
+                FlaggedPosts::insert(post_id, true);
+
+                Self::deposit_event(RawEvent::PostFlagged(post_id));
+            }
+
+            Ok(())
+        }
+
+        /// Set number of reports after which a post is flagged for moderation.
+        /// Posts already past the new threshold are not flagged retroactively.
+        fn set_post_report_threshold(origin, new_post_report_threshold: u32) -> dispatch::Result {
+            ensure_root(origin)?;
+
+            // Threshold must be reachable
+            ensure!(
+                new_post_report_threshold as usize <= MAX_REPORTS_PER_POST,
+                ERROR_POST_REPORT_THRESHOLD_TOO_HIGH
+            );
+
+            // Hold on to old value
+            let old_post_report_threshold = PostReportThreshold::get();
+
+            // Update post report threshold
+            PostReportThreshold::put(new_post_report_threshold);
+
+            // Generate event
+            Self::deposit_event(RawEvent::PostReportThresholdSet(old_post_report_threshold, new_post_report_threshold));
+
+            Ok(())
+        }
+
+        /// Ban or suspend account from forum, replacing any previous sanction of account.
+        /// Sanction lasts until `expires_at` block, if given, or until lifted.
+        /// Suspensions must be given an expiry, only bans can be indefinite.
+        /// Category moderators cannot sanction other moderators, nor replace active
+        /// sanctions imposed by someone else.
+        fn sanction_account(origin, account_id: T::AccountId, kind: SanctionKind, expires_at: Option<T::BlockNumber>) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Signed by forum SUDO or moderator of some category
+            let is_forum_sudo = Self::ensure_is_forum_sudo_or_any_category_moderator(&who)?;
+
+            // Forum SUDO cannot be sanctioned
+            ensure!(Self::forum_sudo() != Some(account_id.clone()), ERROR_CANNOT_SANCTION_FORUM_SUDO);
+
+            let current_block = <system::Module<T>>::block_number();
+
+            if !is_forum_sudo {
+                // Moderators can only be sanctioned by forum SUDO
+                ensure!(
+                    <NumModeratedCategoriesByAccount<T>>::get(&account_id) == 0,
+                    ERROR_CANNOT_SANCTION_CATEGORY_MODERATOR
+                );
+
+                // Active sanction of someone else cannot be replaced
+                if let Some(sanction) = <SanctionByAccount<T>>::get(&account_id) {
+                    ensure!(
+                        sanction.sanctioned_by == who || !sanction.is_active(&current_block),
+                        ERROR_SANCTION_NOT_IMPOSED_BY_ORIGIN
+                    );
+                }
+            }
+
+            // Suspension has an expiry
+
+            ensure!(kind != SanctionKind::Suspension || expires_at.is_some(), ERROR_SUSPENSION_WITHOUT_EXPIRY);
+
+            // Expiry is in the future
+
+            if let Some(ref expires_at) = expires_at {
+                ensure!(current_block < *expires_at, ERROR_SANCTION_EXPIRY_NOT_IN_FUTURE);
+            }
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            <SanctionByAccount<T>>::insert(&account_id, Sanction {
+                kind,
+                sanctioned_by: who,
+                sanctioned_at: current_block,
+                expires_at
+            });
+
+            <SanctionedAccounts<T>>::insert(&account_id, true);
+
+            // Generate event
+            Self::deposit_event(RawEvent::AccountSanctioned(account_id, kind, expires_at));
+
+            Ok(())
+        }
+
+        /// Lift sanction of account, whether or not it has expired.
+        /// Category moderators can only lift sanctions they imposed.
+        fn lift_sanction(origin, account_id: T::AccountId) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Signed by forum SUDO or moderator of some category
+            let is_forum_sudo = Self::ensure_is_forum_sudo_or_any_category_moderator(&who)?;
+
+            // Account is sanctioned
+            let sanction = <SanctionByAccount<T>>::get(&account_id).ok_or(ERROR_ACCOUNT_NOT_SANCTIONED)?;
+
+            // Sanction was imposed by origin, unless origin is forum SUDO
+            ensure!(is_forum_sudo || sanction.sanctioned_by == who, ERROR_SANCTION_NOT_IMPOSED_BY_ORIGIN);
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            <SanctionByAccount<T>>::remove(&account_id);
+
+            <SanctionedAccounts<T>>::remove(&account_id);
+
+            // Generate event
+            Self::deposit_event(RawEvent::SanctionLifted(account_id));
+
+            Ok(())
+        }
+
+        /// Add or remove account from appeal resolvers, only forum sudo can do this.
+        fn set_appeal_resolver(origin, account_id: T::AccountId, is_resolver: bool) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Not signed by forum SUDO
+            Self::ensure_is_forum_sudo(&who)?;
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            if is_resolver {
+                <AppealResolvers<T>>::insert(&account_id, true);
+            } else {
+                <AppealResolvers<T>>::remove(&account_id);
+            }
+
+            // Generate event
+            Self::deposit_event(RawEvent::AppealResolverUpdated(account_id, is_resolver));
+
+            Ok(())
+        }
+
+        /// Appeal moderation of own thread or post, within appeal window of moderation.
+        /// Sanctioned accounts can still appeal, as the appealed moderation may be what got them sanctioned.
+        fn file_appeal(origin, subject: AppealSubject, text: Vec<u8>) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Check that account is active forum user, whether or not it is sanctioned
+            Self::ensure_is_active_forum_user(&who)?;
+
+            // Validate text
+            Self::ensure_appeal_text_is_valid(&text)?;
+
+            // Get moderation of subject
+            let (author_id, moderation, moderation_nr) = Self::ensure_appeal_subject_is_moderated(subject)?;
+
+            // Signed by author of subject
+            ensure!(author_id == who, ERROR_ORIGIN_NOT_AUTHOR_OF_APPEAL_SUBJECT);
+
+            // Moderation is still appealable
+            ensure!(
+                <system::Module<T>>::block_number() <= moderation.moderated_at.block.saturating_add(<AppealWindow<T>>::get()),
+                ERROR_APPEAL_WINDOW_EXPIRED
+            );
+
+            // Moderation is not already appealed
+            if let Some(appeal_id) = AppealIdBySubject::get(subject) {
+                ensure!(
+                    <AppealById<T>>::get(appeal_id).moderation_nr != moderation_nr,
+                    ERROR_MODERATION_ALREADY_APPEALED
+                );
+            }
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            let appeal_id = NextAppealId::get();
+
+            <AppealById<T>>::insert(appeal_id, Appeal {
+                id: appeal_id,
+                subject,
+                moderation_nr,
+                appellant_id: who,
+                text,
+                filed_at: Self::current_block_and_time(),
+                resolution: None
+            });
+
+            AppealIdBySubject::insert(subject, appeal_id);
+
+            // Update next appeal id
+            NextAppealId::mutate(|n| {
+                *n += 1;
+            });
+
+            // Generate event
+            Self::deposit_event(RawEvent::AppealFiled(appeal_id, subject));
+
+            Ok(())
+        }
+
+        /// Resolve appeal, by an appeal resolver other than the moderator of the appealed moderation.
+        /// Overturning the appeal reverses the moderation.
+        fn resolve_appeal(origin, appeal_id: AppealId, outcome: AppealOutcome, rationale: Vec<u8>) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Signed by appeal resolver
+            ensure!(<AppealResolvers<T>>::get(&who), ERROR_ORIGIN_NOT_APPEAL_RESOLVER);
+
+            // Make sure appeal exists and is unresolved
+            ensure!(<AppealById<T>>::exists(appeal_id), ERROR_APPEAL_DOES_NOT_EXIST);
+
+            let appeal = <AppealById<T>>::get(appeal_id);
+
+            ensure!(appeal.resolution.is_none(), ERROR_APPEAL_ALREADY_RESOLVED);
+
+            // Appealed moderation is still in place
+            let (_, moderation, moderation_nr) = Self::ensure_appeal_subject_is_moderated(appeal.subject)?;
+
+            ensure!(moderation_nr == appeal.moderation_nr, ERROR_APPEALED_MODERATION_ALREADY_REVERSED);
+
+            // Resolver did not do the appealed moderation
+            ensure!(moderation.moderator_id != who, ERROR_APPEAL_RESOLVER_IS_ORIGINAL_MODERATOR);
+
+            // Rationale valid
+            Self::ensure_appeal_text_is_valid(&rationale)?;
+
+            // Moderation can be reversed
+            if outcome == AppealOutcome::Overturned {
+                match appeal.subject {
+                    AppealSubject::Thread(thread_id) => {
+                        let path = Self::build_category_tree_path(<ThreadById<T>>::get(thread_id).category_id);
+
+                        Self::ensure_can_mutate_in_path_leaf(&path)?;
+                    },
+                    AppealSubject::Post(post_id) => {
+                        Self::ensure_thread_is_mutable(&<PostById<T>>::get(post_id).thread_id)?;
+                    }
+                }
+            }
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            let resolved_at = Self::current_block_and_time();
+
+            if outcome == AppealOutcome::Overturned {
+                let reversal = ModerationAction {
+                    moderated_at: resolved_at.clone(),
+                    moderator_id: who.clone(),
+                    rationale: rationale.clone()
+                };
+
+                match appeal.subject {
+                    AppealSubject::Thread(thread_id) => {
+                        Self::reverse_thread_moderation(<ThreadById<T>>::get(thread_id), reversal);
+                    },
+                    AppealSubject::Post(post_id) => {
+                        Self::reverse_post_moderation(<PostById<T>>::get(post_id), reversal);
+                    }
+                }
+            }
+
+            <AppealById<T>>::mutate(appeal_id, |a| {
+                a.resolution = Some(AppealResolution {
+                    resolved_at,
+                    resolver_id: who,
+                    outcome,
+                    rationale
+                });
+            });
+
+            // Generate event
+            Self::deposit_event(RawEvent::AppealResolved(appeal_id, outcome));
+
+            Ok(())
+        }
+
+        /// Delete own post, replacing its text with an empty tombstone.
+        fn delete_post(origin, post_id: PostId) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Check that account is forum member
+            Self::ensure_is_forum_member(&who)?;
+
+            // Make sure there exists a mutable post with post id `post_id`
+            let post = Self::ensure_post_is_mutable(&post_id)?;
+
+            // Signer does not match creator of post with identifier postId
+            ensure!(post.author_id == who, ERROR_ACCOUNT_DOES_NOT_MATCH_POST_AUTHOR);
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            <PostById<T>>::mutate(post_id, |p| {
+                p.current_text = vec![];
+                p.text_change_history = vec![];
+                p.deleted_at = Some(Self::current_block_and_time());
+            });
+
+            // Update unmoderated and deleted post count of corresponding thread
+            <ThreadById<T>>::mutate(post.thread_id, |t| {
+                t.num_unmoderated_posts -= 1;
+                t.num_deleted_posts += 1;
+            });
+
+            // Refund deposit of post
+            T::Currency::unreserve(&who, <PostDepositById<T>>::take(post_id));
+
+            // Deleted posts are no longer reported
+            Self::clear_post_reports(post_id);
+
+            // Remove post from index of author
+            <PostIdByAuthorAndNr<T>>::remove(&who, post.nr_by_author);
+
+            // Generate event
+            Self::deposit_event(RawEvent::PostDeletedByAuthor(post_id, who, post.thread_id));
+
+            Ok(())
+        }
+
+        /// Delete own thread, replacing its title with an empty tombstone and clearing its title history.
+        /// Posts in the thread are kept, but no further posts can be added.
+        fn delete_thread(origin, thread_id: ThreadId) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Check that account is forum member
+            Self::ensure_is_forum_member(&who)?;
+
+            // Make sure thread exists and is mutable
+            let thread = Self::ensure_thread_is_mutable(&thread_id)?;
+
+            // Signer does not match creator of thread
+            ensure!(thread.author_id == who, ERROR_ACCOUNT_DOES_NOT_MATCH_THREAD_AUTHOR);
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            <ThreadById<T>>::mutate(thread_id, |t| {
+                t.title = vec![];
+                t.title_change_history = vec![];
+                t.deleted_at = Some(Self::current_block_and_time());
+            });
+
+            // Update unmoderated and deleted thread count of corresponding category
+            <CategoryById<T>>::mutate(thread.category_id, |c| {
+                c.num_direct_unmoderated_threads -= 1;
+                c.num_direct_deleted_threads += 1;
+            });
+
+            // Refund deposit of thread
+            T::Currency::unreserve(&who, <ThreadDepositById<T>>::take(thread_id));
+
+            // Posts can no longer be deleted or moderated
+            Self::release_posts_of_thread(&thread, false);
+
+            // Deleted threads are not pinned
+            Self::unpin_thread_from_category(thread.category_id, thread_id);
+
+            // Remove thread from index of author
+            <ThreadIdByAuthorAndNr<T>>::remove(&who, thread.nr_by_author);
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadDeletedByAuthor(thread_id, who, thread.category_id));
+
+            Ok(())
+        }
+
+        /// React to post, replacing any previous reaction of origin to the post.
+        /// Passing `None` as reaction withdraws the previous reaction.
+        fn react_to_post(origin, post_id: PostId, reaction: Option<PostReaction>) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Check that account is forum member
+            Self::ensure_is_forum_member(&who)?;
+
+            // Make sure there exists a mutable post with post id `post_id`
+            let post = Self::ensure_post_is_mutable(&post_id)?;
+
+            // Make sure reaction is actually being changed
+            let old_reaction = <PostReactionByAccount<T>>::get(post_id, &who);
+
+            ensure!(old_reaction != reaction, ERROR_POST_REACTION_NOT_CHANGED);
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            if let Some(old_reaction) = old_reaction {
+                PostReactionCount::mutate(post_id, old_reaction, |n| {
+                    *n -= 1;
+                });
+            }
+
+            match reaction {
+                Some(new_reaction) => {
+                    <PostReactionByAccount<T>>::insert(post_id, &who, new_reaction);
+
+                    PostReactionCount::mutate(post_id, new_reaction, |n| {
+                        *n += 1;
+                    });
+                },
+                None => <PostReactionByAccount<T>>::remove(post_id, &who)
+            };
+
+            // Generate event
+            Self::deposit_event(RawEvent::PostReacted(who, post_id, post.thread_id, reaction));
+
+            Ok(())
+        }
+
+        /// Vote for alternative with given index in poll of thread, at most once per account.
+        fn vote_on_poll(origin, thread_id: ThreadId, alternative_index: u32) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Check that account is forum member
+            Self::ensure_is_forum_member(&who)?;
+
+            // Make sure thread exists and is mutable
+            let thread = Self::ensure_thread_is_mutable(&thread_id)?;
+
+            // Make sure thread has a poll which is still open
+            let poll = thread.poll.ok_or(ERROR_THREAD_HAS_NO_POLL)?;
+
+            ensure!(
+                <system::Module<T>>::block_number() < poll.ends_at,
+                ERROR_POLL_ENDED
+            );
+
+            // Make sure alternative exists
+            ensure!(
+                (alternative_index as usize) < poll.alternatives.len(),
+                ERROR_POLL_ALTERNATIVE_DOES_NOT_EXIST
+            );
+
+            // Make sure account has not already voted
+            ensure!(
+                <PollVoteByAccount<T>>::get(thread_id, &who).is_none(),
+                ERROR_ALREADY_VOTED_ON_POLL
+            );
+
+            /*
+             * Here we are safe to mutate
+             */
 
-            // Update moderated and unmoderated post count of corresponding thread
-            <ThreadById<T>>::mutate(post.thread_id, |t| {
-                t.num_unmoderated_posts -= 1;
-                t.num_moderated_posts += 1;
+            <ThreadById<T>>::mutate(thread_id, |t| {
+                if let Some(ref mut poll) = t.poll {
+                    poll.alternatives[alternative_index as usize].vote_count += 1;
+                }
             });
 
+            <PollVoteByAccount<T>>::insert(thread_id, &who, alternative_index);
+
             // Generate event
-            Self::deposit_event(RawEvent::PostModerated(post.id));
+            Self::deposit_event(RawEvent::PollVoted(who, thread_id, alternative_index));
 
             Ok(())
         }
@@ -1061,6 +3028,278 @@ impl<T: Trait> Module<T> {
         )
     }
 
+    fn ensure_appeal_text_is_valid(text: &Vec<u8>) -> dispatch::Result {
+        AppealTextConstraint::get().ensure_valid(
+            text.len(),
+            ERROR_APPEAL_TEXT_TOO_SHORT,
+            ERROR_APPEAL_TEXT_TOO_LONG,
+        )
+    }
+
+    fn ensure_post_report_text_is_valid(text: &Vec<u8>) -> dispatch::Result {
+        PostReportTextConstraint::get().ensure_valid(
+            text.len(),
+            ERROR_POST_REPORT_TEXT_TOO_SHORT,
+            ERROR_POST_REPORT_TEXT_TOO_LONG,
+        )
+    }
+
+    fn ensure_poll_is_valid(poll: &PollInput<T::BlockNumber>) -> dispatch::Result {
+        PollQuestionConstraint::get().ensure_valid(
+            poll.question.len(),
+            ERROR_POLL_QUESTION_TOO_SHORT,
+            ERROR_POLL_QUESTION_TOO_LONG,
+        )?;
+
+        PollAlternativesConstraint::get().ensure_valid(
+            poll.alternatives.len(),
+            ERROR_POLL_TOO_FEW_ALTERNATIVES,
+            ERROR_POLL_TOO_MANY_ALTERNATIVES,
+        )?;
+
+        for alternative in poll.alternatives.iter() {
+            PollAlternativeTextConstraint::get().ensure_valid(
+                alternative.len(),
+                ERROR_POLL_ALTERNATIVE_TEXT_TOO_SHORT,
+                ERROR_POLL_ALTERNATIVE_TEXT_TOO_LONG,
+            )?;
+        }
+
+        ensure!(
+            poll.ends_at > <system::Module<T>>::block_number(),
+            ERROR_POLL_END_NOT_IN_FUTURE
+        );
+
+        Ok(())
+    }
+
+    /// Vote counts of poll of given thread, ordered as the alternatives of the poll.
+    /// Returns `None` if thread does not exist or has no poll.
+    pub fn poll_vote_counts(thread_id: ThreadId) -> Option<Vec<u32>> {
+        if !<ThreadById<T>>::exists(thread_id) {
+            return None;
+        }
+
+        <ThreadById<T>>::get(thread_id).poll.map(|poll| {
+            poll.alternatives
+                .iter()
+                .map(|alternative| alternative.vote_count)
+                .collect()
+        })
+    }
+
+    /// Returns author and current moderation of appeal subject, along with number
+    /// of reversed moderations of subject, provided subject exists and is moderated.
+    fn ensure_appeal_subject_is_moderated(
+        subject: AppealSubject,
+    ) -> Result<
+        (
+            T::AccountId,
+            ModerationAction<T::BlockNumber, T::Moment, T::AccountId>,
+            u32,
+        ),
+        &'static str,
+    > {
+        match subject {
+            AppealSubject::Thread(thread_id) => {
+                let thread = Self::ensure_thread_exists(&thread_id)?;
+
+                let moderation = thread.moderation.ok_or(ERROR_THREAD_NOT_MODERATED)?;
+
+                Ok((
+                    thread.author_id,
+                    moderation,
+                    thread.moderation_history.len() as u32,
+                ))
+            }
+            AppealSubject::Post(post_id) => {
+                let post = Self::ensure_post_exists(&post_id)?;
+
+                let moderation = post.moderation.ok_or(ERROR_POST_NOT_MODERATED)?;
+
+                Ok((
+                    post.author_id,
+                    moderation,
+                    post.moderation_history.len() as u32,
+                ))
+            }
+        }
+    }
+
+    /// Moves moderation of given moderated thread into its moderation history,
+    /// and makes all required state updates, including generating event.
+    fn reverse_thread_moderation(
+        thread: Thread<T::BlockNumber, T::Moment, T::AccountId>,
+        reversal: ModerationAction<T::BlockNumber, T::Moment, T::AccountId>,
+    ) {
+        if let Some(original) = thread.moderation {
+            let event = RawEvent::ThreadUnmoderated(
+                thread.id,
+                reversal.moderator_id.clone(),
+                thread.category_id,
+                T::Hashing::hash(&reversal.rationale),
+            );
+
+            <ThreadById<T>>::mutate(thread.id, |t| {
+                t.moderation = None;
+                t.moderation_history
+                    .push(ReversedModerationAction { original, reversal });
+            });
+
+            // Update moderation/umoderation count of corresponding category
+            <CategoryById<T>>::mutate(thread.category_id, |c| {
+                c.num_direct_moderated_threads -= 1;
+                c.num_direct_unmoderated_threads += 1;
+            });
+
+            Self::deposit_event(event);
+        }
+    }
+
+    /// Moves moderation of given moderated post into its moderation history,
+    /// and makes all required state updates, including generating event.
+    fn reverse_post_moderation(
+        post: Post<T::BlockNumber, T::Moment, T::AccountId>,
+        reversal: ModerationAction<T::BlockNumber, T::Moment, T::AccountId>,
+    ) {
+        if let Some(original) = post.moderation {
+            let event = RawEvent::PostUnmoderated(
+                post.id,
+                reversal.moderator_id.clone(),
+                post.thread_id,
+                T::Hashing::hash(&reversal.rationale),
+            );
+
+            <PostById<T>>::mutate(post.id, |p| {
+                p.moderation = None;
+                p.moderation_history
+                    .push(ReversedModerationAction { original, reversal });
+            });
+
+            // Update moderated and unmoderated post count of corresponding thread
+            <ThreadById<T>>::mutate(post.thread_id, |t| {
+                t.num_moderated_posts -= 1;
+                t.num_unmoderated_posts += 1;
+            });
+
+            Self::deposit_event(event);
+        }
+    }
+
+    /// Blocks in which account added posts which still count towards its post rate limit.
+    fn recent_post_blocks(account_id: &T::AccountId) -> Vec<T::BlockNumber> {
+        let current_block = <system::Module<T>>::block_number();
+        let period = <PostRateLimitPeriod<T>>::get();
+
+        <RecentPostBlocksByAccount<T>>::get(account_id)
+            .into_iter()
+            .filter(|block| current_block < block.saturating_add(period))
+            .collect()
+    }
+
+    fn ensure_post_rate_limit_not_exceeded(account_id: &T::AccountId) -> dispatch::Result {
+        let max_posts = MaxPostsPerRateLimitPeriod::get();
+
+        if max_posts > 0 {
+            ensure!(
+                (Self::recent_post_blocks(account_id).len() as u32) < max_posts,
+                ERROR_POST_RATE_LIMIT_EXCEEDED
+            );
+        }
+
+        Ok(())
+    }
+
+    fn ensure_thread_rate_limit_not_exceeded(account_id: &T::AccountId) -> dispatch::Result {
+        let min_blocks = <MinBlocksBetweenThreads<T>>::get();
+
+        if !min_blocks.is_zero() {
+            if let Some(last_thread_block) = <LastThreadBlockByAccount<T>>::get(account_id) {
+                ensure!(
+                    <system::Module<T>>::block_number()
+                        >= last_thread_block.saturating_add(min_blocks),
+                    ERROR_THREAD_RATE_LIMIT_EXCEEDED
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Records post by account in current block, pruning posts which no longer count towards limit.
+    fn record_post_for_rate_limit(account_id: &T::AccountId) {
+        if MaxPostsPerRateLimitPeriod::get() > 0 {
+            let mut recent_post_blocks = Self::recent_post_blocks(account_id);
+
+            recent_post_blocks.push(<system::Module<T>>::block_number());
+
+            <RecentPostBlocksByAccount<T>>::insert(account_id, recent_post_blocks);
+        }
+    }
+
+    /// Records thread created by account in current block.
+    fn record_thread_for_rate_limit(account_id: &T::AccountId) {
+        if !<MinBlocksBetweenThreads<T>>::get().is_zero() {
+            <LastThreadBlockByAccount<T>>::insert(account_id, <system::Module<T>>::block_number());
+        }
+    }
+
+    /// Removes reports of post, and its flag if it is flagged.
+    fn clear_post_reports(post_id: PostId) {
+        <PostReports<T>>::remove(post_id);
+
+        FlaggedPosts::remove(post_id);
+    }
+
+    /// Returns deposits still reserved for posts in thread to their authors, and clears
+    /// reports of the posts, as they can no longer be deleted or moderated.
+    /// Deposits of posts by the thread author are slashed if `slash_posts_of_author` is set,
+    /// and refunded otherwise. Deposits of moderated or deleted posts have already been taken.
+    ///
+    /// The work done is proportional to the number of posts ever added to the thread.
+    /// This is accepted, as every such post was paid for with a post deposit and counted
+    /// towards the post rate limit of its author, so a thread cannot be made costly
+    /// to delete or moderate without those costs being borne by whoever added the posts.
+    fn release_posts_of_thread(
+        thread: &Thread<T::BlockNumber, T::Moment, T::AccountId>,
+        slash_posts_of_author: bool,
+    ) {
+        for nr in 1..=thread.num_posts_ever_created() {
+            if let Some(post_id) = PostIdByThreadAndNr::get(thread.id, nr) {
+                if <PostDepositById<T>>::exists(post_id) {
+                    let post = <PostById<T>>::get(post_id);
+                    let post_deposit = <PostDepositById<T>>::take(post_id);
+
+                    if slash_posts_of_author && post.author_id == thread.author_id {
+                        Self::slash_deposit(&post.author_id, post_deposit);
+                    } else {
+                        T::Currency::unreserve(&post.author_id, post_deposit);
+                    }
+                }
+
+                Self::clear_post_reports(post_id);
+            }
+        }
+    }
+
+    /// Removes thread from pinned threads of category, if it is pinned there.
+    fn unpin_thread_from_category(category_id: CategoryId, thread_id: ThreadId) {
+        PinnedThreadsByCategory::mutate(category_id, |pinned_threads| {
+            pinned_threads.retain(|pinned_thread_id| *pinned_thread_id != thread_id);
+        });
+    }
+
+    /// Slashes the moderation fraction of a deposit reserved from `author_id`,
+    /// and returns the remainder to the author.
+    fn slash_deposit(author_id: &T::AccountId, deposit: BalanceOf<T>) {
+        let slash_amount = ModerationDepositSlashRatio::get() * deposit;
+
+        // Slashed funds are burned
+        let _ = T::Currency::slash_reserved(author_id, slash_amount);
+
+        T::Currency::unreserve(author_id, deposit.saturating_sub(slash_amount));
+    }
+
     fn current_block_and_time() -> BlockchainTimestamp<T::BlockNumber, T::Moment> {
         BlockchainTimestamp {
             block: <system::Module<T>>::block_number(),
@@ -1068,6 +3307,43 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    fn set_thread_lock(origin: T::Origin, thread_id: ThreadId, locked: bool) -> dispatch::Result {
+        // Check that its a valid signature
+        let who = ensure_signed(origin)?;
+
+        // Make sure thread exists and is mutable
+        let thread = Self::ensure_thread_is_mutable(&thread_id)?;
+
+        // Signed by forum SUDO or moderator of category
+        let category_tree_path = Self::build_category_tree_path(thread.category_id);
+
+        Self::ensure_is_forum_sudo_or_category_moderator(&who, &category_tree_path)?;
+
+        // Lock is actually being changed
+        if locked {
+            ensure!(!thread.locked, ERROR_THREAD_LOCKED);
+        } else {
+            ensure!(thread.locked, ERROR_THREAD_NOT_LOCKED);
+        }
+
+        /*
+         * Here we are safe to mutate
+         */
+
+        <ThreadById<T>>::mutate(thread_id, |t| {
+            t.locked = locked;
+        });
+
+        // Generate event
+        if locked {
+            Self::deposit_event(RawEvent::ThreadLocked(thread_id, who));
+        } else {
+            Self::deposit_event(RawEvent::ThreadUnlocked(thread_id, who));
+        }
+
+        Ok(())
+    }
+
     fn ensure_post_is_mutable(
         post_id: &PostId,
     ) -> Result<Post<T::BlockNumber, T::Moment, T::AccountId>, &'static str> {
@@ -1077,6 +3353,9 @@ impl<T: Trait> Module<T> {
         // and is unmoderated
         ensure!(post.moderation.is_none(), ERROR_POST_MODERATED);
 
+        // and is not deleted
+        ensure!(post.deleted_at.is_none(), ERROR_POST_DELETED);
+
         // and make sure thread is mutable
         Self::ensure_thread_is_mutable(&post.thread_id)?;
 
@@ -1102,6 +3381,9 @@ impl<T: Trait> Module<T> {
         // and is unmoderated
         ensure!(thread.moderation.is_none(), ERROR_THREAD_MODERATED);
 
+        // and is not deleted
+        ensure!(thread.deleted_at.is_none(), ERROR_THREAD_DELETED);
+
         // and corresponding category is mutable
         Self::ensure_catgory_is_mutable(thread.category_id)?;
 
@@ -1135,18 +3417,141 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
-    fn ensure_is_forum_member(
+    /// Whether account is forum sudo, or moderator of some category in the given path,
+    /// i.e. of the leaf category or any of its ancestors.
+    fn is_forum_sudo_or_category_moderator(
+        account_id: &T::AccountId,
+        category_tree_path: &CategoryTreePath<T::BlockNumber, T::Moment, T::AccountId>,
+    ) -> bool {
+        let is_forum_sudo = <ForumSudo<T>>::get().map_or(false, |forum_sudo_account| {
+            forum_sudo_account == *account_id
+        });
+
+        is_forum_sudo
+            || category_tree_path
+                .iter()
+                .any(|c| <CategoryModerators<T>>::get(c.id, account_id))
+    }
+
+    fn ensure_is_forum_sudo_or_category_moderator(
+        account_id: &T::AccountId,
+        category_tree_path: &CategoryTreePath<T::BlockNumber, T::Moment, T::AccountId>,
+    ) -> dispatch::Result {
+        ensure!(
+            Self::is_forum_sudo_or_category_moderator(account_id, category_tree_path),
+            ERROR_ORIGIN_NOT_FORUM_SUDO_OR_CATEGORY_MODERATOR
+        );
+
+        Ok(())
+    }
+
+    fn ensure_is_forum_member(account_id: &T::AccountId) -> Result<ForumUserOf<T>, &'static str> {
+        let forum_user = Self::ensure_is_active_forum_user(account_id)?;
+
+        // Make sure account is not sanctioned
+        if let Some(sanction) = <SanctionByAccount<T>>::get(account_id) {
+            if sanction.is_active(&<system::Module<T>>::block_number()) {
+                return Err(match sanction.kind {
+                    SanctionKind::Ban => ERROR_ACCOUNT_BANNED,
+                    SanctionKind::Suspension => ERROR_ACCOUNT_SUSPENDED,
+                });
+            }
+        }
+
+        Ok(forum_user)
+    }
+
+    /// Ensures account is an active user in the membership registry, regardless of sanctions.
+    fn ensure_is_active_forum_user(
         account_id: &T::AccountId,
-    ) -> Result<ForumUser<T::AccountId>, &'static str> {
+    ) -> Result<ForumUserOf<T>, &'static str> {
         let forum_user_query = T::MembershipRegistry::get_forum_user(account_id);
 
-        if let Some(forum_user) = forum_user_query {
-            Ok(forum_user)
-        } else {
-            Err(ERROR_NOT_FORUM_USER)
+        let forum_user = forum_user_query.ok_or(ERROR_NOT_FORUM_USER)?;
+
+        // Make sure user is active in registry
+        ensure!(forum_user.is_active, ERROR_FORUM_USER_NOT_ACTIVE);
+
+        Ok(forum_user)
+    }
+
+    /// Sanctions which currently apply, along with the sanctioned accounts.
+    pub fn active_sanctions() -> Vec<(T::AccountId, Sanction<T::BlockNumber, T::AccountId>)> {
+        let current_block = <system::Module<T>>::block_number();
+
+        <SanctionedAccounts<T>>::enumerate()
+            .filter_map(|(account_id, _)| {
+                <SanctionByAccount<T>>::get(&account_id)
+                    .filter(|sanction| sanction.is_active(&current_block))
+                    .map(|sanction| (account_id, sanction))
+            })
+            .collect()
+    }
+
+    /// Ensures account is forum sudo or moderator of some category,
+    /// returning whether it is forum sudo.
+    fn ensure_is_forum_sudo_or_any_category_moderator(
+        account_id: &T::AccountId,
+    ) -> Result<bool, &'static str> {
+        let is_forum_sudo = Self::forum_sudo() == Some(account_id.clone());
+
+        let is_any_category_moderator = <NumModeratedCategoriesByAccount<T>>::get(account_id) > 0;
+
+        ensure!(
+            is_forum_sudo || is_any_category_moderator,
+            ERROR_ORIGIN_NOT_FORUM_SUDO_OR_ANY_CATEGORY_MODERATOR
+        );
+
+        Ok(is_forum_sudo)
+    }
+
+    fn ensure_posting_policy_allows_thread(
+        account_id: &T::AccountId,
+        category_id: CategoryId,
+        category_tree_path: &CategoryTreePath<T::BlockNumber, T::Moment, T::AccountId>,
+    ) -> dispatch::Result {
+        if Self::is_forum_sudo_or_category_moderator(account_id, category_tree_path) {
+            return Ok(());
+        }
+
+        match PostingPolicyByCategory::get(category_id) {
+            PostingPolicy::Open => Ok(()),
+            PostingPolicy::ModeratorsOnlyThreads => Err(ERROR_THREADS_RESTRICTED_TO_MODERATORS),
+            PostingPolicy::Restricted => {
+                Self::ensure_is_on_category_posting_allowlist(account_id, category_id)
+            }
+        }
+    }
+
+    fn ensure_posting_policy_allows_post(
+        account_id: &T::AccountId,
+        category_id: CategoryId,
+        category_tree_path: &CategoryTreePath<T::BlockNumber, T::Moment, T::AccountId>,
+    ) -> dispatch::Result {
+        if Self::is_forum_sudo_or_category_moderator(account_id, category_tree_path) {
+            return Ok(());
+        }
+
+        match PostingPolicyByCategory::get(category_id) {
+            PostingPolicy::Open | PostingPolicy::ModeratorsOnlyThreads => Ok(()),
+            PostingPolicy::Restricted => {
+                Self::ensure_is_on_category_posting_allowlist(account_id, category_id)
+            }
         }
     }
 
+    fn ensure_is_on_category_posting_allowlist(
+        account_id: &T::AccountId,
+        category_id: CategoryId,
+    ) -> dispatch::Result {
+        ensure!(
+            <CategoryPostingAllowlist<T>>::get(category_id, account_id),
+            ERROR_ACCOUNT_NOT_ON_CATEGORY_POSTING_ALLOWLIST
+        );
+
+        Ok(())
+    }
+
     fn ensure_catgory_is_mutable(category_id: CategoryId) -> dispatch::Result {
         let category_tree_path = Self::build_category_tree_path(category_id);
 
@@ -1172,17 +3577,205 @@ impl<T: Trait> Module<T> {
     ) -> dispatch::Result {
         Self::ensure_can_mutate_in_path_leaf(category_tree_path)?;
 
-        // Does adding a new category exceed maximum depth
-        let depth_of_new_category = 1 + 1 + category_tree_path.len();
+        // Does adding a new category exceed maximum depth,
+        // where the depth of the new category is the number of its ancestors.
+        let depth_of_new_category = category_tree_path.len();
 
         ensure!(
-            depth_of_new_category <= MAX_CATEGORY_DEPTH as usize,
+            depth_of_new_category <= MaxCategoryDepth::get() as usize,
             ERROR_MAX_VALID_CATEGORY_DEPTH_EXCEEDED
         );
 
         Ok(())
     }
 
+    /// Number of levels of subcategories below given category,
+    /// i.e. 0 if it has no subcategories.
+    fn category_subtree_height(category_id: CategoryId) -> usize {
+        Self::subcategory_ids(category_id)
+            .into_iter()
+            .map(|subcategory_id| 1 + Self::category_subtree_height(subcategory_id))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Path from category to root of category tree, starting with category itself.
+    /// Empty if category does not exist.
+    pub fn category_tree_path(
+        category_id: CategoryId,
+    ) -> Vec<Category<T::BlockNumber, T::Moment, T::AccountId>> {
+        Self::ensure_valid_category_and_build_category_tree_path(category_id).unwrap_or_default()
+    }
+
+    /// Root categories, in the order they became root categories.
+    pub fn root_categories() -> Vec<Category<T::BlockNumber, T::Moment, T::AccountId>> {
+        RootCategoryIds::get()
+            .into_iter()
+            .map(|category_id| <CategoryById<T>>::get(category_id))
+            .collect()
+    }
+
+    /// Direct subcategories of category, in order of child number.
+    pub fn subcategories(
+        category_id: CategoryId,
+    ) -> Vec<Category<T::BlockNumber, T::Moment, T::AccountId>> {
+        Self::subcategory_ids(category_id)
+            .into_iter()
+            .map(|subcategory_id| <CategoryById<T>>::get(subcategory_id))
+            .collect()
+    }
+
+    /// Threads in category with number in category from `first_nr`, over at most `max` numbers,
+    /// capped at `MAX_QUERY_PAGE_SIZE`. Numbers of threads moved out of the category are skipped.
+    pub fn threads_in_category(
+        category_id: CategoryId,
+        first_nr: u32,
+        max: u32,
+    ) -> Vec<Thread<T::BlockNumber, T::Moment, T::AccountId>> {
+        Self::query_page(first_nr, max)
+            .filter_map(|nr| ThreadIdByCategoryAndNr::get(category_id, nr))
+            .map(|thread_id| <ThreadById<T>>::get(thread_id))
+            .collect()
+    }
+
+    /// Posts in thread with number in thread from `first_nr`, over at most `max` numbers,
+    /// capped at `MAX_QUERY_PAGE_SIZE`.
+    pub fn posts_in_thread(
+        thread_id: ThreadId,
+        first_nr: u32,
+        max: u32,
+    ) -> Vec<Post<T::BlockNumber, T::Moment, T::AccountId>> {
+        Self::query_page(first_nr, max)
+            .filter_map(|nr| PostIdByThreadAndNr::get(thread_id, nr))
+            .map(|post_id| <PostById<T>>::get(post_id))
+            .collect()
+    }
+
+    /// Range of numbers covered by a page of a paginated query.
+    fn query_page(first_nr: u32, max: u32) -> core::ops::Range<u32> {
+        first_nr..first_nr.saturating_add(max.min(MAX_QUERY_PAGE_SIZE))
+    }
+
+    /// Counters of category, if it exists.
+    pub fn category_counters(category_id: CategoryId) -> Option<CategoryCounters> {
+        if !<CategoryById<T>>::exists(category_id) {
+            return None;
+        }
+
+        let category = <CategoryById<T>>::get(category_id);
+
+        Some(CategoryCounters {
+            num_direct_subcategories: category.num_direct_subcategories,
+            num_direct_unmoderated_threads: category.num_direct_unmoderated_threads,
+            num_direct_moderated_threads: category.num_direct_moderated_threads,
+            num_direct_deleted_threads: category.num_direct_deleted_threads,
+            num_direct_moved_threads: category.num_direct_moved_threads,
+            num_threads_created: category.num_threads_created(),
+        })
+    }
+
+    /// Counters of thread, if it exists.
+    pub fn thread_counters(thread_id: ThreadId) -> Option<ThreadCounters> {
+        if !<ThreadById<T>>::exists(thread_id) {
+            return None;
+        }
+
+        let thread = <ThreadById<T>>::get(thread_id);
+
+        Some(ThreadCounters {
+            num_unmoderated_posts: thread.num_unmoderated_posts,
+            num_moderated_posts: thread.num_moderated_posts,
+            num_deleted_posts: thread.num_deleted_posts,
+            num_posts_ever_created: thread.num_posts_ever_created(),
+        })
+    }
+
+    /// Posts which have reached the report threshold, and have not since been moderated or deleted.
+    pub fn flagged_posts() -> Vec<PostId> {
+        FlaggedPosts::enumerate()
+            .map(|(post_id, _)| post_id)
+            .collect()
+    }
+
+    /// Threads not deleted by account, with number by author from `first_nr`,
+    /// over at most `max` numbers, capped at `MAX_QUERY_PAGE_SIZE`.
+    pub fn threads_by_author(
+        account_id: &T::AccountId,
+        first_nr: u32,
+        max: u32,
+    ) -> Vec<Thread<T::BlockNumber, T::Moment, T::AccountId>> {
+        Self::query_page(first_nr, max)
+            .filter_map(|nr| <ThreadIdByAuthorAndNr<T>>::get(account_id, nr))
+            .map(|thread_id| <ThreadById<T>>::get(thread_id))
+            .collect()
+    }
+
+    /// Posts not deleted by account, with number by author from `first_nr`,
+    /// over at most `max` numbers, capped at `MAX_QUERY_PAGE_SIZE`.
+    pub fn posts_by_author(
+        account_id: &T::AccountId,
+        first_nr: u32,
+        max: u32,
+    ) -> Vec<Post<T::BlockNumber, T::Moment, T::AccountId>> {
+        Self::query_page(first_nr, max)
+            .filter_map(|nr| <PostIdByAuthorAndNr<T>>::get(account_id, nr))
+            .map(|post_id| <PostById<T>>::get(post_id))
+            .collect()
+    }
+
+    /// Identifiers of direct subcategories of category, in order of child number.
+    pub fn subcategory_ids(category_id: CategoryId) -> Vec<CategoryId> {
+        let num_subcategories_created =
+            <CategoryById<T>>::get(category_id).num_subcategories_created();
+
+        (1..=num_subcategories_created)
+            .filter_map(|child_nr| CategoryIdByParentAndNr::get(category_id, child_nr))
+            .collect()
+    }
+
+    /// Adds category to child index of its parent, or to root categories.
+    fn index_category_position(
+        category_id: CategoryId,
+        position_in_parent_category: &Option<ChildPositionInParentCategory>,
+    ) {
+        match position_in_parent_category {
+            Some(position) => CategoryIdByParentAndNr::insert(
+                position.parent_id,
+                position.child_nr_in_parent_category,
+                category_id,
+            ),
+            None => RootCategoryIds::mutate(|root_category_ids| {
+                root_category_ids.push(category_id);
+            }),
+        }
+    }
+
+    /// Removes category from child index of its parent, or from root categories.
+    fn unindex_category_position(
+        category_id: CategoryId,
+        position_in_parent_category: &Option<ChildPositionInParentCategory>,
+    ) {
+        match position_in_parent_category {
+            Some(position) => CategoryIdByParentAndNr::remove(
+                position.parent_id,
+                position.child_nr_in_parent_category,
+            ),
+            None => RootCategoryIds::mutate(|root_category_ids| {
+                root_category_ids.retain(|root_category_id| *root_category_id != category_id);
+            }),
+        }
+    }
+
+    /// Depth of the deepest existing category, or 0 if there are no categories.
+    /// Requires visiting every category.
+    fn greatest_category_depth() -> usize {
+        (0..NextCategoryId::get())
+            .filter(|id| <CategoryById<T>>::exists(id))
+            .map(|id| Self::build_category_tree_path(id).len() - 1)
+            .max()
+            .unwrap_or(0)
+    }
+
     fn ensure_valid_category_and_build_category_tree_path(
         category_id: CategoryId,
     ) -> Result<CategoryTreePath<T::BlockNumber, T::Moment, T::AccountId>, &'static str> {
@@ -1241,6 +3834,7 @@ impl<T: Trait> Module<T> {
         category_id: CategoryId,
         title: &Vec<u8>,
         author_id: &T::AccountId,
+        poll: Option<PollInput<T::BlockNumber>>,
     ) -> Thread<T::BlockNumber, T::Moment, T::AccountId> {
         // Get category
         let category = <CategoryById<T>>::get(category_id);
@@ -1253,16 +3847,40 @@ impl<T: Trait> Module<T> {
             title: title.clone(),
             category_id: category_id,
             nr_in_category: category.num_threads_created() + 1,
+            nr_by_author: <NumThreadsCreatedByAuthor<T>>::get(author_id) + 1,
             moderation: None,
             num_unmoderated_posts: 0,
             num_moderated_posts: 0,
+            num_deleted_posts: 0,
             created_at: Self::current_block_and_time(),
             author_id: author_id.clone(),
+            deleted_at: None,
+            poll: poll.map(|poll| Poll {
+                question: poll.question,
+                alternatives: poll
+                    .alternatives
+                    .into_iter()
+                    .map(|text| PollAlternative {
+                        text,
+                        vote_count: 0,
+                    })
+                    .collect(),
+                ends_at: poll.ends_at,
+            }),
+            title_change_history: vec![],
+            locked: false,
+            moderation_history: vec![],
         };
 
         // Store thread
         <ThreadById<T>>::insert(new_thread_id, new_thread.clone());
 
+        ThreadIdByCategoryAndNr::insert(category_id, new_thread.nr_in_category, new_thread_id);
+
+        <ThreadIdByAuthorAndNr<T>>::insert(author_id, new_thread.nr_by_author, new_thread_id);
+
+        <NumThreadsCreatedByAuthor<T>>::insert(author_id, new_thread.nr_by_author);
+
         // Update next thread id
         NextThreadId::mutate(|n| {
             *n += 1;
@@ -1277,11 +3895,12 @@ impl<T: Trait> Module<T> {
     }
 
     /// Creates and ads a new post ot the given thread, and makes all required state updates
-    /// `thread_id` must be valid
+    /// `thread_id` must be valid, as must `reply_to` if present
     fn add_new_post(
         thread_id: ThreadId,
         text: &Vec<u8>,
         author_id: &T::AccountId,
+        reply_to: Option<PostId>,
     ) -> Post<T::BlockNumber, T::Moment, T::AccountId> {
         // Get thread
         let thread = <ThreadById<T>>::get(thread_id);
@@ -1293,16 +3912,34 @@ impl<T: Trait> Module<T> {
             id: new_post_id,
             thread_id: thread_id,
             nr_in_thread: thread.num_posts_ever_created() + 1,
+            nr_by_author: <NumPostsCreatedByAuthor<T>>::get(author_id) + 1,
             current_text: text.clone(),
             moderation: None,
             text_change_history: vec![],
             created_at: Self::current_block_and_time(),
             author_id: author_id.clone(),
+            deleted_at: None,
+            reply_to,
+            num_replies: 0,
+            moderation_history: vec![],
         };
 
         // Store post
         <PostById<T>>::insert(new_post_id, new_post.clone());
 
+        PostIdByThreadAndNr::insert(thread_id, new_post.nr_in_thread, new_post_id);
+
+        <PostIdByAuthorAndNr<T>>::insert(author_id, new_post.nr_by_author, new_post_id);
+
+        <NumPostsCreatedByAuthor<T>>::insert(author_id, new_post.nr_by_author);
+
+        // Update reply count of replied post
+        if let Some(replied_post_id) = reply_to {
+            <PostById<T>>::mutate(replied_post_id, |p| {
+                p.num_replies += 1;
+            });
+        }
+
         // Update next post id
         NextPostId::mutate(|n| {
             *n += 1;
@@ -10,7 +10,7 @@ use runtime_primitives::{
     traits::{BlakeTwo256, IdentityLookup},
     Perbill,
 };
-use srml_support::{impl_outer_origin, parameter_types};
+use srml_support::{impl_outer_event, impl_outer_origin, parameter_types};
 
 /// Module which has a full Substrate module for
 /// mocking behaviour of MembershipRegistry
@@ -19,9 +19,18 @@ pub mod registry {
     use super::*;
     // use srml_support::*;
 
+    #[derive(Encode, Decode, Debug, Copy, Clone, PartialEq, Eq)]
+    pub enum MemberRole {
+        CouncilMember,
+        WorkingGroupLead,
+    }
+
     #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
     pub struct Member<AccountId> {
         pub id: AccountId,
+        pub handle: Vec<u8>,
+        pub roles: Vec<MemberRole>,
+        pub is_active: bool,
     }
 
     decl_storage! {
@@ -40,14 +49,27 @@ pub mod registry {
         pub fn add_member(member: &Member<T::AccountId>) {
             <ForumUserById<T>>::insert(member.id.clone(), member.clone());
         }
+
+        pub fn set_member_active(id: &T::AccountId, is_active: bool) {
+            <ForumUserById<T>>::mutate(id, |m| {
+                m.is_active = is_active;
+            });
+        }
     }
 
     impl<T: Trait> ForumUserRegistry<T::AccountId> for Module<T> {
-        fn get_forum_user(id: &T::AccountId) -> Option<ForumUser<T::AccountId>> {
+        type Role = MemberRole;
+
+        fn get_forum_user(id: &T::AccountId) -> Option<ForumUser<T::AccountId, MemberRole>> {
             if <ForumUserById<T>>::exists(id) {
                 let m = <ForumUserById<T>>::get(id);
 
-                Some(ForumUser { id: m.id })
+                Some(ForumUser {
+                    id: m.id,
+                    handle: m.handle,
+                    roles: m.roles,
+                    is_active: m.is_active,
+                })
             } else {
                 None
             }
@@ -61,6 +83,16 @@ impl_outer_origin! {
     pub enum Origin for Runtime {}
 }
 
+mod forum_mod {
+    pub use crate::Event;
+}
+
+impl_outer_event! {
+    pub enum TestEvent for Runtime {
+        forum_mod<T>,
+    }
+}
+
 // Workaround for https://github.com/rust-lang/rust/issues/26925 . Remove when sorted.
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Runtime;
@@ -70,6 +102,9 @@ parameter_types! {
     pub const MaximumBlockLength: u32 = 2 * 1024;
     pub const AvailableBlockRatio: Perbill = Perbill::one();
     pub const MinimumPeriod: u64 = 5;
+    pub const ExistentialDeposit: u64 = 0;
+    pub const TransferFee: u64 = 0;
+    pub const CreationFee: u64 = 0;
 }
 
 impl system::Trait for Runtime {
@@ -83,7 +118,7 @@ impl system::Trait for Runtime {
     type Lookup = IdentityLookup<Self::AccountId>;
     type Header = Header;
     // type WeightMultiplierUpdate = ();
-    type Event = ();
+    type Event = TestEvent;
     type BlockHashCount = BlockHashCount;
     type MaximumBlockWeight = MaximumBlockWeight;
     type MaximumBlockLength = MaximumBlockLength;
@@ -97,9 +132,22 @@ impl timestamp::Trait for Runtime {
     type MinimumPeriod = MinimumPeriod;
 }
 
-impl Trait for Runtime {
+impl balances::Trait for Runtime {
+    type Balance = u64;
+    type OnFreeBalanceZero = ();
+    type OnNewAccount = ();
     type Event = ();
+    type DustRemoval = ();
+    type TransferPayment = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type TransferFee = TransferFee;
+    type CreationFee = CreationFee;
+}
+
+impl Trait for Runtime {
+    type Event = TestEvent;
     type MembershipRegistry = registry::TestMembershipRegistryModule;
+    type Currency = Balances;
 }
 
 #[derive(Clone)]
@@ -127,6 +175,14 @@ pub const INVLAID_THREAD_ID: ThreadId = 444;
 
 pub const INVLAID_POST_ID: ThreadId = 555;
 
+pub const CATEGORY_MODERATOR_ID: <Runtime as system::Trait>::AccountId = 666;
+
+pub const CATEGORY_MODERATOR_ORIGIN: OriginType = OriginType::Signed(CATEGORY_MODERATOR_ID);
+
+pub const APPEAL_RESOLVER_ID: <Runtime as system::Trait>::AccountId = 777;
+
+pub const APPEAL_RESOLVER_ORIGIN: OriginType = OriginType::Signed(APPEAL_RESOLVER_ID);
+
 pub fn generate_text(len: usize) -> Vec<u8> {
     vec![b'x'; len]
 }
@@ -155,6 +211,16 @@ pub fn good_rationale() -> Vec<u8> {
     b"This post violates our community rules".to_vec()
 }
 
+pub fn good_poll(
+    ends_at: <Runtime as system::Trait>::BlockNumber,
+) -> PollInput<<Runtime as system::Trait>::BlockNumber> {
+    PollInput {
+        question: b"Which option do we choose?".to_vec(),
+        alternatives: vec![b"First option".to_vec(), b"Second option".to_vec()],
+        ends_at,
+    }
+}
+
 /*
  * These test fixtures can be heavily refactored to avoid repotition, needs macros, and event
  * assertions are also missing.
@@ -209,6 +275,7 @@ pub struct CreateThreadFixture {
     pub category_id: CategoryId,
     pub title: Vec<u8>,
     pub text: Vec<u8>,
+    pub poll: Option<PollInput<<Runtime as system::Trait>::BlockNumber>>,
     pub result: dispatch::Result,
 }
 
@@ -219,7 +286,8 @@ impl CreateThreadFixture {
                 mock_origin(self.origin.clone()),
                 self.category_id,
                 self.title.clone(),
-                self.text.clone()
+                self.text.clone(),
+                self.poll.clone()
             ),
             self.result
         )
@@ -230,6 +298,7 @@ pub struct CreatePostFixture {
     pub origin: OriginType,
     pub thread_id: ThreadId,
     pub text: Vec<u8>,
+    pub reply_to: Option<PostId>,
     pub result: dispatch::Result,
 }
 
@@ -239,17 +308,31 @@ impl CreatePostFixture {
             TestForumModule::add_post(
                 mock_origin(self.origin.clone()),
                 self.thread_id,
-                self.text.clone()
+                self.text.clone(),
+                self.reply_to
             ),
             self.result
         )
     }
 }
 
+pub const FORUM_MEMBER_ID: <Runtime as system::Trait>::AccountId = 123;
+
+pub const FORUM_MEMBER_BALANCE: u64 = 1000;
+
 pub fn create_forum_member() -> OriginType {
-    let member_id = 123;
-    let new_member = registry::Member { id: member_id };
+    create_forum_member_with_id(FORUM_MEMBER_ID)
+}
+
+pub fn create_forum_member_with_id(member_id: <Runtime as system::Trait>::AccountId) -> OriginType {
+    let new_member = registry::Member {
+        id: member_id,
+        handle: b"forum_member".to_vec(),
+        roles: vec![],
+        is_active: true,
+    };
     registry::TestMembershipRegistryModule::add_member(&new_member);
+    let _ = Balances::deposit_creating(&member_id, FORUM_MEMBER_BALANCE);
     OriginType::Signed(member_id)
 }
 
@@ -278,6 +361,7 @@ pub fn assert_create_thread(
         category_id,
         title: good_thread_title(),
         text: good_thread_text(),
+        poll: None,
         result: expected_result,
     }
     .call_and_assert();
@@ -292,6 +376,7 @@ pub fn assert_create_post(
         origin: forum_sudo,
         thread_id,
         text: good_thread_text(),
+        reply_to: None,
         result: expected_result,
     }
     .call_and_assert();
@@ -322,6 +407,7 @@ pub fn create_root_category_and_thread(
         category_id,
         title: good_thread_title(),
         text: good_thread_text(),
+        poll: None,
         result: Ok(()),
     }
     .call_and_assert();
@@ -339,6 +425,7 @@ pub fn create_root_category_and_thread_and_post(
         origin: member_origin.clone(),
         thread_id: thread_id.clone(),
         text: good_post_text(),
+        reply_to: None,
         result: Ok(()),
     }
     .call_and_assert();
@@ -362,6 +449,199 @@ pub fn moderate_post(
     TestForumModule::moderate_post(mock_origin(forum_sudo), post_id, rationale)
 }
 
+pub fn unmoderate_thread(
+    origin: OriginType,
+    thread_id: ThreadId,
+    rationale: Vec<u8>,
+) -> dispatch::Result {
+    TestForumModule::unmoderate_thread(mock_origin(origin), thread_id, rationale)
+}
+
+pub fn unmoderate_post(
+    origin: OriginType,
+    post_id: PostId,
+    rationale: Vec<u8>,
+) -> dispatch::Result {
+    TestForumModule::unmoderate_post(mock_origin(origin), post_id, rationale)
+}
+
+pub fn set_category_posting_policy(
+    origin: OriginType,
+    category_id: CategoryId,
+    policy: PostingPolicy,
+) -> dispatch::Result {
+    TestForumModule::set_category_posting_policy(mock_origin(origin), category_id, policy)
+}
+
+pub fn set_category_posting_allowlist(
+    origin: OriginType,
+    category_id: CategoryId,
+    account_id: <Runtime as system::Trait>::AccountId,
+    is_allowed: bool,
+) -> dispatch::Result {
+    TestForumModule::set_category_posting_allowlist(
+        mock_origin(origin),
+        category_id,
+        account_id,
+        is_allowed,
+    )
+}
+
+pub fn sanction_account(
+    origin: OriginType,
+    account_id: <Runtime as system::Trait>::AccountId,
+    kind: SanctionKind,
+    expires_at: Option<<Runtime as system::Trait>::BlockNumber>,
+) -> dispatch::Result {
+    TestForumModule::sanction_account(mock_origin(origin), account_id, kind, expires_at)
+}
+
+pub fn lift_sanction(
+    origin: OriginType,
+    account_id: <Runtime as system::Trait>::AccountId,
+) -> dispatch::Result {
+    TestForumModule::lift_sanction(mock_origin(origin), account_id)
+}
+
+pub fn report_post(origin: OriginType, post_id: PostId, text: Option<Vec<u8>>) -> dispatch::Result {
+    TestForumModule::report_post(mock_origin(origin), post_id, PostReportReason::Spam, text)
+}
+
+pub fn set_post_report_threshold(
+    origin: OriginType,
+    new_post_report_threshold: u32,
+) -> dispatch::Result {
+    TestForumModule::set_post_report_threshold(mock_origin(origin), new_post_report_threshold)
+}
+
+pub fn set_appeal_resolver(
+    origin: OriginType,
+    account_id: <Runtime as system::Trait>::AccountId,
+    is_resolver: bool,
+) -> dispatch::Result {
+    TestForumModule::set_appeal_resolver(mock_origin(origin), account_id, is_resolver)
+}
+
+pub fn good_appeal_text() -> Vec<u8> {
+    b"I did not break any rules".to_vec()
+}
+
+pub fn file_appeal(origin: OriginType, subject: AppealSubject) -> dispatch::Result {
+    TestForumModule::file_appeal(mock_origin(origin), subject, good_appeal_text())
+}
+
+pub fn resolve_appeal(
+    origin: OriginType,
+    appeal_id: AppealId,
+    outcome: AppealOutcome,
+) -> dispatch::Result {
+    TestForumModule::resolve_appeal(mock_origin(origin), appeal_id, outcome, good_rationale())
+}
+
+pub fn edit_thread_title(
+    origin: OriginType,
+    thread_id: ThreadId,
+    new_title: Vec<u8>,
+) -> dispatch::Result {
+    TestForumModule::edit_thread_title(mock_origin(origin), thread_id, new_title)
+}
+
+pub fn lock_thread(origin: OriginType, thread_id: ThreadId) -> dispatch::Result {
+    TestForumModule::lock_thread(mock_origin(origin), thread_id)
+}
+
+pub fn unlock_thread(origin: OriginType, thread_id: ThreadId) -> dispatch::Result {
+    TestForumModule::unlock_thread(mock_origin(origin), thread_id)
+}
+
+pub fn pin_thread(origin: OriginType, thread_id: ThreadId) -> dispatch::Result {
+    TestForumModule::pin_thread(mock_origin(origin), thread_id)
+}
+
+pub fn unpin_thread(origin: OriginType, thread_id: ThreadId) -> dispatch::Result {
+    TestForumModule::unpin_thread(mock_origin(origin), thread_id)
+}
+
+pub fn reorder_pinned_threads(
+    origin: OriginType,
+    category_id: CategoryId,
+    new_order: Vec<ThreadId>,
+) -> dispatch::Result {
+    TestForumModule::reorder_pinned_threads(mock_origin(origin), category_id, new_order)
+}
+
+pub fn move_thread_to_category(
+    origin: OriginType,
+    thread_id: ThreadId,
+    new_category_id: CategoryId,
+) -> dispatch::Result {
+    TestForumModule::move_thread_to_category(mock_origin(origin), thread_id, new_category_id)
+}
+
+pub fn delete_thread(origin: OriginType, thread_id: ThreadId) -> dispatch::Result {
+    TestForumModule::delete_thread(mock_origin(origin), thread_id)
+}
+
+pub fn delete_post(origin: OriginType, post_id: PostId) -> dispatch::Result {
+    TestForumModule::delete_post(mock_origin(origin), post_id)
+}
+
+pub fn vote_on_poll(
+    origin: OriginType,
+    thread_id: ThreadId,
+    alternative_index: u32,
+) -> dispatch::Result {
+    TestForumModule::vote_on_poll(mock_origin(origin), thread_id, alternative_index)
+}
+
+pub fn react_to_post(
+    origin: OriginType,
+    post_id: PostId,
+    reaction: Option<PostReaction>,
+) -> dispatch::Result {
+    TestForumModule::react_to_post(mock_origin(origin), post_id, reaction)
+}
+
+pub fn set_max_category_depth(origin: OriginType, new_max_category_depth: u16) -> dispatch::Result {
+    TestForumModule::set_max_category_depth(mock_origin(origin), new_max_category_depth)
+}
+
+pub fn set_category_moderator(
+    forum_sudo: OriginType,
+    category_id: CategoryId,
+    account_id: <Runtime as system::Trait>::AccountId,
+    is_moderator: bool,
+) -> dispatch::Result {
+    TestForumModule::set_category_moderator(
+        mock_origin(forum_sudo),
+        category_id,
+        account_id,
+        is_moderator,
+    )
+}
+
+pub fn move_category(
+    forum_sudo: OriginType,
+    category_id: CategoryId,
+    new_parent: Option<CategoryId>,
+) -> dispatch::Result {
+    TestForumModule::move_category(mock_origin(forum_sudo), category_id, new_parent)
+}
+
+pub fn edit_category_text(
+    origin: OriginType,
+    category_id: CategoryId,
+    new_title: Option<Vec<u8>>,
+    new_description: Option<Vec<u8>>,
+) -> dispatch::Result {
+    TestForumModule::edit_category_text(
+        mock_origin(origin),
+        category_id,
+        new_title,
+        new_description,
+    )
+}
+
 pub fn archive_category(forum_sudo: OriginType, category_id: CategoryId) -> dispatch::Result {
     TestForumModule::update_category(mock_origin(forum_sudo), category_id, Some(true), None)
 }
@@ -388,7 +668,7 @@ pub fn assert_not_forum_sudo_cannot_update_category(
         let category_id = create_root_category(origin.clone());
         assert_eq!(
             update_operation(NOT_FORUM_SUDO_ORIGIN, category_id),
-            Err(ERROR_ORIGIN_NOT_FORUM_SUDO)
+            Err(ERROR_ORIGIN_NOT_FORUM_SUDO_OR_CATEGORY_MODERATOR)
         );
     });
 }
@@ -411,6 +691,8 @@ pub fn default_genesis_config() -> GenesisConfig<Runtime> {
 
         forum_sudo: 33,
 
+        max_category_depth: 3,
+
         category_title_constraint: InputValidationLengthConstraint {
             min: 10,
             max_min_diff: 140,
@@ -439,10 +721,51 @@ pub fn default_genesis_config() -> GenesisConfig<Runtime> {
         post_moderation_rationale_constraint: InputValidationLengthConstraint {
             min: 10,
             max_min_diff: 2000,
-        }, // JUST GIVING UP ON ALL THIS FOR NOW BECAUSE ITS TAKING TOO LONG
+        },
+
+        poll_question_constraint: InputValidationLengthConstraint {
+            min: 3,
+            max_min_diff: 200,
+        },
+
+        poll_alternative_text_constraint: InputValidationLengthConstraint {
+            min: 1,
+            max_min_diff: 100,
+        },
 
-           // Extra genesis fields
-           //initial_forum_sudo: Some(143)
+        poll_alternatives_constraint: InputValidationLengthConstraint {
+            min: 2,
+            max_min_diff: 8,
+        },
+
+        thread_deposit: 10,
+        post_deposit: 5,
+        moderation_deposit_slash_ratio: Perbill::from_percent(50),
+
+        max_posts_per_rate_limit_period: 10,
+        post_rate_limit_period: 10,
+        min_blocks_between_threads: 0,
+
+        post_report_text_constraint: InputValidationLengthConstraint {
+            min: 1,
+            max_min_diff: 200,
+        },
+
+        post_report_threshold: 3,
+
+        appeal_by_id: vec![],
+        next_appeal_id: 1,
+
+        appeal_text_constraint: InputValidationLengthConstraint {
+            min: 10,
+            max_min_diff: 2000,
+        },
+
+        appeal_window: 100,
+        // JUST GIVING UP ON ALL THIS FOR NOW BECAUSE ITS TAKING TOO LONG
+
+        // Extra genesis fields
+        //initial_forum_sudo: Some(143)
     }
 }
 
@@ -475,12 +798,26 @@ pub fn genesis_config(
     post_by_id: &RuntimeMap<PostId, RuntimePost>,
     next_post_id: u64,
     forum_sudo: <Runtime as system::Trait>::AccountId,
+    max_category_depth: u16,
     category_title_constraint: &InputValidationLengthConstraint,
     category_description_constraint: &InputValidationLengthConstraint,
     thread_title_constraint: &InputValidationLengthConstraint,
     post_text_constraint: &InputValidationLengthConstraint,
     thread_moderation_rationale_constraint: &InputValidationLengthConstraint,
     post_moderation_rationale_constraint: &InputValidationLengthConstraint,
+    poll_question_constraint: &InputValidationLengthConstraint,
+    poll_alternative_text_constraint: &InputValidationLengthConstraint,
+    poll_alternatives_constraint: &InputValidationLengthConstraint,
+    thread_deposit: u64,
+    post_deposit: u64,
+    moderation_deposit_slash_ratio: Perbill,
+    max_posts_per_rate_limit_period: u32,
+    post_rate_limit_period: u64,
+    min_blocks_between_threads: u64,
+    post_report_text_constraint: &InputValidationLengthConstraint,
+    post_report_threshold: u32,
+    appeal_text_constraint: &InputValidationLengthConstraint,
+    appeal_window: u64,
 ) -> GenesisConfig<Runtime> {
     GenesisConfig::<Runtime> {
         category_by_id: category_by_id.clone(),
@@ -490,12 +827,28 @@ pub fn genesis_config(
         post_by_id: post_by_id.clone(),
         next_post_id: next_post_id,
         forum_sudo: forum_sudo,
+        max_category_depth,
         category_title_constraint: category_title_constraint.clone(),
         category_description_constraint: category_description_constraint.clone(),
         thread_title_constraint: thread_title_constraint.clone(),
         post_text_constraint: post_text_constraint.clone(),
         thread_moderation_rationale_constraint: thread_moderation_rationale_constraint.clone(),
         post_moderation_rationale_constraint: post_moderation_rationale_constraint.clone(),
+        poll_question_constraint: poll_question_constraint.clone(),
+        poll_alternative_text_constraint: poll_alternative_text_constraint.clone(),
+        poll_alternatives_constraint: poll_alternatives_constraint.clone(),
+        thread_deposit,
+        post_deposit,
+        moderation_deposit_slash_ratio,
+        max_posts_per_rate_limit_period,
+        post_rate_limit_period,
+        min_blocks_between_threads,
+        post_report_text_constraint: post_report_text_constraint.clone(),
+        post_report_threshold,
+        appeal_by_id: vec![],
+        next_appeal_id: 1,
+        appeal_text_constraint: appeal_text_constraint.clone(),
+        appeal_window,
     }
 }
 
@@ -524,7 +877,20 @@ pub fn build_test_externalities(config: GenesisConfig<Runtime>) -> runtime_io::T
     t.into()
 }
 
-// pub type System = system::Module<Runtime>;
+pub type System = system::Module<Runtime>;
+
+/// Events generated by forum module, in the order they were generated.
+pub fn forum_events() -> Vec<crate::Event<Runtime>> {
+    System::events()
+        .into_iter()
+        .filter_map(|record| match record.event {
+            TestEvent::forum_mod(event) => Some(event),
+            _ => None,
+        })
+        .collect()
+}
+
+pub type Balances = balances::Module<Runtime>;
 
 /// Export forum module on a test runtime
 pub type TestForumModule = Module<Runtime>;
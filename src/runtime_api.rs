@@ -0,0 +1,47 @@
+//! Runtime API for read queries of the forum.
+//!
+//! A runtime exposes it by implementing `ForumApi` with `impl_runtime_apis!`,
+//! forwarding each call to the query function of the same name on `Module`.
+//! Fields of returned categories, threads and posts are public, so clients
+//! can read them after decoding.
+
+use crate::{
+    Category, CategoryCounters, CategoryId, Post, PostId, Thread, ThreadCounters, ThreadId,
+};
+use codec::Codec;
+use rstd::prelude::*;
+
+client::decl_runtime_apis! {
+    /// Read queries of the forum, so forum pages can be served over RPC in a single call.
+    pub trait ForumApi<AccountId, BlockNumber, Moment>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+        Moment: Codec,
+    {
+        /// Path from category to root of category tree, starting with category itself.
+        /// Empty if category does not exist.
+        fn category_tree_path(category_id: CategoryId) -> Vec<Category<BlockNumber, Moment, AccountId>>;
+
+        /// Root categories, in the order they became root categories.
+        fn root_categories() -> Vec<Category<BlockNumber, Moment, AccountId>>;
+
+        /// Direct subcategories of category, in order of child number.
+        fn subcategories(category_id: CategoryId) -> Vec<Category<BlockNumber, Moment, AccountId>>;
+
+        /// Threads in category with number in category from `first_nr`, over at most `max` numbers.
+        fn threads_in_category(category_id: CategoryId, first_nr: u32, max: u32) -> Vec<Thread<BlockNumber, Moment, AccountId>>;
+
+        /// Posts in thread with number in thread from `first_nr`, over at most `max` numbers.
+        fn posts_in_thread(thread_id: ThreadId, first_nr: u32, max: u32) -> Vec<Post<BlockNumber, Moment, AccountId>>;
+
+        /// Counters of category, if it exists.
+        fn category_counters(category_id: CategoryId) -> Option<CategoryCounters>;
+
+        /// Counters of thread, if it exists.
+        fn thread_counters(thread_id: ThreadId) -> Option<ThreadCounters>;
+
+        /// Posts which have reached the report threshold, and have not since been moderated or deleted.
+        fn flagged_posts() -> Vec<PostId>;
+    }
+}
@@ -6,7 +6,7 @@ use crate::mock::*;
 use srml_support::{assert_err, assert_ok};
 
 /*
-* NB!: No test checks for event emission!!!!
+* NB!: Only thread creation and moderation are checked for event emission, see Events section.
 */
 
 /*
@@ -67,6 +67,52 @@ fn set_forum_sudo_update() {
     });
 }
 
+/*
+ * set_max_category_depth
+ * ==============================================================================
+ */
+
+#[test]
+fn set_max_category_depth_successfully() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        assert_ok!(set_max_category_depth(OriginType::Root, 5));
+        assert_eq!(TestForumModule::max_category_depth(), 5);
+    });
+}
+
+#[test]
+fn cannot_set_max_category_depth_below_existing_category() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let root_category_id = create_root_category(origin.clone());
+        let subcategory_id = create_category(origin.clone(), Some(root_category_id));
+        create_category(origin, Some(subcategory_id));
+
+        assert_ok!(set_max_category_depth(OriginType::Root, 2));
+        assert_err!(
+            set_max_category_depth(OriginType::Root, 1),
+            ERROR_EXISTING_CATEGORY_EXCEEDS_MAX_CATEGORY_DEPTH
+        );
+    });
+}
+
+#[test]
+fn not_root_cannot_set_max_category_depth() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        assert_err!(
+            set_max_category_depth(origin, 5),
+            "bad origin: expected to be a root origin"
+        );
+    });
+}
+
 /*
  * create_category
  * ==============================================================================
@@ -98,6 +144,54 @@ fn create_subcategory_successfully() {
     });
 }
 
+#[test]
+fn subcategories_are_numbered_from_one() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let root_category_id = create_root_category(origin.clone());
+        let first_category_id = create_category(origin.clone(), Some(root_category_id));
+        let second_category_id = create_category(origin, Some(root_category_id));
+
+        assert_eq!(
+            TestForumModule::category_by_id(first_category_id).position_in_parent_category,
+            Some(ChildPositionInParentCategory {
+                parent_id: root_category_id,
+                child_nr_in_parent_category: 1,
+            })
+        );
+        assert_eq!(
+            TestForumModule::category_by_id(second_category_id).position_in_parent_category,
+            Some(ChildPositionInParentCategory {
+                parent_id: root_category_id,
+                child_nr_in_parent_category: 2,
+            })
+        );
+    });
+}
+
+#[test]
+fn cannot_create_subcategory_beyond_max_depth() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+    let max_category_depth = config.max_category_depth;
+
+    build_test_externalities(config).execute_with(|| {
+        let mut parent_id = create_root_category(origin.clone());
+
+        for _ in 0..max_category_depth {
+            parent_id = create_category(origin.clone(), Some(parent_id));
+        }
+
+        assert_create_category(
+            origin,
+            Some(parent_id),
+            Err(ERROR_MAX_VALID_CATEGORY_DEPTH_EXCEEDED),
+        );
+    });
+}
+
 #[test]
 fn create_category_title_too_short() {
     let config = default_genesis_config();
@@ -207,8 +301,12 @@ fn update_category_undelete_and_unarchive() {
                 num_direct_subcategories: 1,
                 num_direct_unmoderated_threads: 0,
                 num_direct_moderated_threads: 0,
+                num_direct_deleted_threads: 0,
+                num_direct_moved_threads: 0,
+                num_direct_moved_subcategories: 0,
                 position_in_parent_category: None,
                 moderator_id: forum_sudo,
+                text_change_history: vec![],
             },
         ),
         // A subcategory of the one above
@@ -226,11 +324,15 @@ fn update_category_undelete_and_unarchive() {
                 num_direct_subcategories: 0,
                 num_direct_unmoderated_threads: 0,
                 num_direct_moderated_threads: 0,
+                num_direct_deleted_threads: 0,
+                num_direct_moved_threads: 0,
+                num_direct_moved_subcategories: 0,
                 position_in_parent_category: Some(ChildPositionInParentCategory {
                     parent_id: 1,
                     child_nr_in_parent_category: 1,
                 }),
                 moderator_id: forum_sudo,
+                text_change_history: vec![],
             },
         ),
     ];
@@ -249,12 +351,26 @@ fn update_category_undelete_and_unarchive() {
         &vec![],                     // post_by_id
         1,                           // next_post_id
         forum_sudo,
+        3, // max_category_depth
+        &sloppy_constraint,
+        &sloppy_constraint,
+        &sloppy_constraint,
         &sloppy_constraint,
         &sloppy_constraint,
         &sloppy_constraint,
         &sloppy_constraint,
         &sloppy_constraint,
         &sloppy_constraint,
+        0,                        // thread_deposit
+        0,                        // post_deposit
+        Perbill::from_percent(0), // moderation_deposit_slash_ratio
+        0,                        // max_posts_per_rate_limit_period
+        0,                        // post_rate_limit_period
+        0,                        // min_blocks_between_threads
+        &sloppy_constraint,
+        0, // post_report_threshold
+        &sloppy_constraint,
+        0, // appeal_window
     );
 
     build_test_externalities(config).execute_with(|| {
@@ -269,6 +385,90 @@ fn update_category_undelete_and_unarchive() {
     });
 }
 
+#[test]
+fn edit_category_text_successfully() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(origin.clone());
+        let new_title = b"Better category title".to_vec();
+
+        assert_ok!(edit_category_text(
+            origin,
+            category_id,
+            Some(new_title.clone()),
+            None
+        ));
+
+        let category = TestForumModule::category_by_id(category_id);
+        assert_eq!(category.title, new_title);
+        assert_eq!(category.description, good_category_description());
+        assert_eq!(category.text_change_history.len(), 1);
+        assert_eq!(category.text_change_history[0].title, good_category_title());
+    });
+}
+
+#[test]
+fn category_text_change_history_is_bounded() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(origin.clone());
+
+        for _ in 0..MAX_CATEGORY_TEXT_CHANGE_HISTORY_LENGTH + 1 {
+            assert_ok!(edit_category_text(
+                origin.clone(),
+                category_id,
+                Some(good_category_title()),
+                None
+            ));
+        }
+
+        assert_eq!(
+            TestForumModule::category_by_id(category_id)
+                .text_change_history
+                .len(),
+            MAX_CATEGORY_TEXT_CHANGE_HISTORY_LENGTH
+        );
+    });
+}
+
+#[test]
+fn edit_category_text_title_too_short() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+    let min_len = config.category_title_constraint.min as usize;
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(origin.clone());
+        assert_err!(
+            edit_category_text(origin, category_id, Some(generate_text(min_len - 1)), None),
+            ERROR_CATEGORY_TITLE_TOO_SHORT
+        );
+    });
+}
+
+#[test]
+fn not_forum_sudo_cannot_edit_category_text() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(origin);
+        assert_err!(
+            edit_category_text(
+                NOT_FORUM_SUDO_ORIGIN,
+                category_id,
+                None,
+                Some(good_category_description())
+            ),
+            ERROR_ORIGIN_NOT_FORUM_SUDO_OR_CATEGORY_MODERATOR
+        );
+    });
+}
+
 /*
  * create_thread
  * ==============================================================================
@@ -294,6 +494,7 @@ fn create_thread_successfully() {
             category_id,
             title: good_thread_title(),
             text: good_thread_text(),
+            poll: None,
             result: Ok(()),
         }
         .call_and_assert();
@@ -315,6 +516,7 @@ fn create_thread_title_too_short() {
             category_id,
             title: generate_text(min_len - 1),
             text: good_thread_text(),
+            poll: None,
             result: Err(ERROR_THREAD_TITLE_TOO_SHORT),
         }
         .call_and_assert();
@@ -336,6 +538,7 @@ fn create_thread_title_too_long() {
             category_id,
             title: generate_text(max_len + 1),
             text: good_thread_text(),
+            poll: None,
             result: Err(ERROR_THREAD_TITLE_TOO_LONG),
         }
         .call_and_assert();
@@ -357,6 +560,7 @@ fn create_thread_text_too_short() {
             category_id,
             title: good_thread_title(),
             text: generate_text(min_len - 1),
+            poll: None,
             result: Err(ERROR_POST_TEXT_TOO_SHORT),
         }
         .call_and_assert();
@@ -378,6 +582,7 @@ fn create_thread_text_too_long() {
             category_id,
             title: good_thread_title(),
             text: generate_text(max_len + 1),
+            poll: None,
             result: Err(ERROR_POST_TEXT_TOO_LONG),
         }
         .call_and_assert();
@@ -407,6 +612,7 @@ fn create_post_text_too_short() {
             origin: member_origin,
             thread_id,
             text: generate_text(min_len - 1),
+            reply_to: None,
             result: Err(ERROR_POST_TEXT_TOO_SHORT),
         }
         .call_and_assert();
@@ -426,6 +632,7 @@ fn create_post_text_too_long() {
             origin: member_origin,
             thread_id,
             text: generate_text(max_len + 1),
+            reply_to: None,
             result: Err(ERROR_POST_TEXT_TOO_LONG),
         }
         .call_and_assert();
@@ -583,7 +790,7 @@ fn not_forum_sudo_cannot_create_subcategory() {
         assert_create_category(
             NOT_FORUM_SUDO_ORIGIN,
             Some(root_category_id),
-            Err(ERROR_ORIGIN_NOT_FORUM_SUDO),
+            Err(ERROR_ORIGIN_NOT_FORUM_SUDO_OR_CATEGORY_MODERATOR),
         );
     });
 }
@@ -617,7 +824,7 @@ fn not_forum_sudo_cannot_moderate_thread() {
         let (_, _, thread_id) = create_root_category_and_thread(origin.clone());
         assert_eq!(
             moderate_thread(NOT_FORUM_SUDO_ORIGIN, thread_id, good_rationale()),
-            Err(ERROR_ORIGIN_NOT_FORUM_SUDO)
+            Err(ERROR_ORIGIN_NOT_FORUM_SUDO_OR_CATEGORY_MODERATOR)
         );
     });
 }
@@ -631,7 +838,108 @@ fn not_forum_sudo_cannot_moderate_post() {
         let (_, _, _, post_id) = create_root_category_and_thread_and_post(origin.clone());
         assert_eq!(
             moderate_post(NOT_FORUM_SUDO_ORIGIN, post_id, good_rationale()),
-            Err(ERROR_ORIGIN_NOT_FORUM_SUDO)
+            Err(ERROR_ORIGIN_NOT_FORUM_SUDO_OR_CATEGORY_MODERATOR)
+        );
+    });
+}
+
+#[test]
+fn not_forum_sudo_cannot_set_category_moderator() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(origin);
+        assert_err!(
+            set_category_moderator(
+                NOT_FORUM_SUDO_ORIGIN,
+                category_id,
+                CATEGORY_MODERATOR_ID,
+                true
+            ),
+            ERROR_ORIGIN_NOT_FORUM_SUDO
+        );
+    });
+}
+
+// Category moderators:
+// -----------------------------------------------------------------------------
+
+#[test]
+fn category_moderator_can_moderate_in_subcategory() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let root_category_id = create_root_category(forum_sudo.clone());
+        assert_ok!(set_category_moderator(
+            forum_sudo,
+            root_category_id,
+            CATEGORY_MODERATOR_ID,
+            true
+        ));
+
+        let subcategory_id = create_category(CATEGORY_MODERATOR_ORIGIN, Some(root_category_id));
+        let thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(create_forum_member(), subcategory_id, Ok(()));
+
+        assert_ok!(moderate_thread(
+            CATEGORY_MODERATOR_ORIGIN,
+            thread_id,
+            good_rationale()
+        ));
+        assert_ok!(archive_category(CATEGORY_MODERATOR_ORIGIN, subcategory_id));
+    });
+}
+
+#[test]
+fn category_moderator_cannot_moderate_in_other_category() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let moderated_category_id = create_root_category(forum_sudo.clone());
+        assert_ok!(set_category_moderator(
+            forum_sudo.clone(),
+            moderated_category_id,
+            CATEGORY_MODERATOR_ID,
+            true
+        ));
+
+        let (_, _, thread_id) = create_root_category_and_thread(forum_sudo);
+        assert_err!(
+            moderate_thread(CATEGORY_MODERATOR_ORIGIN, thread_id, good_rationale()),
+            ERROR_ORIGIN_NOT_FORUM_SUDO_OR_CATEGORY_MODERATOR
+        );
+    });
+}
+
+#[test]
+fn removed_category_moderator_cannot_moderate() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, category_id, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+        assert_ok!(set_category_moderator(
+            forum_sudo.clone(),
+            category_id,
+            CATEGORY_MODERATOR_ID,
+            true
+        ));
+        assert_ok!(set_category_moderator(
+            forum_sudo,
+            category_id,
+            CATEGORY_MODERATOR_ID,
+            false
+        ));
+        assert!(!TestForumModule::is_category_moderator(
+            category_id,
+            CATEGORY_MODERATOR_ID
+        ));
+        assert_err!(
+            moderate_thread(CATEGORY_MODERATOR_ORIGIN, thread_id, good_rationale()),
+            ERROR_ORIGIN_NOT_FORUM_SUDO_OR_CATEGORY_MODERATOR
         );
     });
 }
@@ -650,6 +958,7 @@ fn not_member_cannot_create_thread() {
             category_id: create_root_category(origin),
             title: good_thread_title(),
             text: good_thread_text(),
+            poll: None,
             result: Err(ERROR_NOT_FORUM_USER),
         }
         .call_and_assert();
@@ -667,6 +976,7 @@ fn not_member_cannot_create_post() {
             origin: NOT_MEMBER_ORIGIN,
             thread_id,
             text: good_post_text(),
+            reply_to: None,
             result: Err(ERROR_NOT_FORUM_USER),
         }
         .call_and_assert();
@@ -718,6 +1028,7 @@ fn cannot_create_thread_with_invalid_category_id() {
             category_id: INVLAID_CATEGORY_ID,
             title: good_thread_title(),
             text: good_thread_text(),
+            poll: None,
             result: Err(ERROR_CATEGORY_DOES_NOT_EXIST),
         }
         .call_and_assert();
@@ -733,6 +1044,7 @@ fn cannot_create_post_with_invalid_thread_id() {
             origin: create_forum_member(),
             thread_id: INVLAID_THREAD_ID,
             text: good_post_text(),
+            reply_to: None,
             result: Err(ERROR_THREAD_DOES_NOT_EXIST),
         }
         .call_and_assert();
@@ -979,3 +1291,2266 @@ fn cannot_edit_post_in_moderated_thread() {
 // TODO impl
 // #[test]
 // fn cannot_edit_moderated_post() {}
+
+// Deletion by author
+// -----------------------------------------------------------------------------
+
+#[test]
+fn delete_post_successfully() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id, post_id) =
+            create_root_category_and_thread_and_post(forum_sudo);
+        assert_ok!(delete_post(member_origin.clone(), post_id));
+
+        let post = TestForumModule::post_by_id(post_id);
+        assert!(post.current_text.is_empty());
+        assert!(post.deleted_at.is_some());
+
+        let thread = TestForumModule::thread_by_id(thread_id);
+        assert_eq!(thread.num_unmoderated_posts, 1);
+        assert_eq!(thread.num_deleted_posts, 1);
+
+        // New posts still get a fresh number in the thread
+        let next_post_id = TestForumModule::next_post_id();
+        assert_create_post(member_origin, thread_id, Ok(()));
+        assert_eq!(TestForumModule::post_by_id(next_post_id).nr_in_thread, 3);
+    });
+}
+
+#[test]
+fn not_author_cannot_delete_post() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, _, post_id) = create_root_category_and_thread_and_post(forum_sudo);
+        assert_err!(
+            delete_post(create_forum_member_with_id(124), post_id),
+            ERROR_ACCOUNT_DOES_NOT_MATCH_POST_AUTHOR
+        );
+    });
+}
+
+#[test]
+fn cannot_edit_deleted_post() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) = create_root_category_and_thread_and_post(forum_sudo);
+        assert_ok!(delete_post(member_origin.clone(), post_id));
+        assert_err!(
+            TestForumModule::edit_post_text(mock_origin(member_origin), post_id, good_post_text()),
+            ERROR_POST_DELETED
+        );
+    });
+}
+
+#[test]
+fn delete_thread_successfully() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, category_id, thread_id) = create_root_category_and_thread(forum_sudo);
+        assert_ok!(edit_thread_title(
+            member_origin.clone(),
+            thread_id,
+            b"Retitled thread".to_vec()
+        ));
+        assert_ok!(delete_thread(member_origin.clone(), thread_id));
+
+        let thread = TestForumModule::thread_by_id(thread_id);
+        assert!(thread.title.is_empty());
+        assert!(thread.title_change_history.is_empty());
+        assert!(thread.deleted_at.is_some());
+
+        let category = TestForumModule::category_by_id(category_id);
+        assert_eq!(category.num_direct_unmoderated_threads, 0);
+        assert_eq!(category.num_direct_deleted_threads, 1);
+
+        assert_create_post(member_origin, thread_id, Err(ERROR_THREAD_DELETED));
+    });
+}
+
+#[test]
+fn not_author_cannot_delete_thread() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread(forum_sudo);
+        assert_err!(
+            delete_thread(create_forum_member_with_id(124), thread_id),
+            ERROR_ACCOUNT_DOES_NOT_MATCH_THREAD_AUTHOR
+        );
+    });
+}
+
+// Reactions
+// -----------------------------------------------------------------------------
+
+#[test]
+fn react_to_post_successfully() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) = create_root_category_and_thread_and_post(forum_sudo);
+        let other_member_origin = create_forum_member_with_id(124);
+
+        assert_ok!(react_to_post(
+            member_origin,
+            post_id,
+            Some(PostReaction::Upvote)
+        ));
+        assert_ok!(react_to_post(
+            other_member_origin.clone(),
+            post_id,
+            Some(PostReaction::Upvote)
+        ));
+        assert_eq!(
+            TestForumModule::post_reaction_count(post_id, PostReaction::Upvote),
+            2
+        );
+
+        // Change reaction
+        assert_ok!(react_to_post(
+            other_member_origin.clone(),
+            post_id,
+            Some(PostReaction::Like)
+        ));
+        assert_eq!(
+            TestForumModule::post_reaction_count(post_id, PostReaction::Upvote),
+            1
+        );
+        assert_eq!(
+            TestForumModule::post_reaction_count(post_id, PostReaction::Like),
+            1
+        );
+
+        // Withdraw reaction
+        assert_ok!(react_to_post(other_member_origin, post_id, None));
+        assert_eq!(
+            TestForumModule::post_reaction_count(post_id, PostReaction::Like),
+            0
+        );
+        assert_eq!(
+            TestForumModule::post_reaction_by_account(post_id, 124u64),
+            None
+        );
+    });
+}
+
+#[test]
+fn cannot_repeat_same_reaction_to_post() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) = create_root_category_and_thread_and_post(forum_sudo);
+        assert_ok!(react_to_post(
+            member_origin.clone(),
+            post_id,
+            Some(PostReaction::Like)
+        ));
+        assert_err!(
+            react_to_post(member_origin, post_id, Some(PostReaction::Like)),
+            ERROR_POST_REACTION_NOT_CHANGED
+        );
+    });
+}
+
+#[test]
+fn not_member_cannot_react_to_post() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, _, post_id) = create_root_category_and_thread_and_post(forum_sudo);
+        assert_err!(
+            react_to_post(NOT_MEMBER_ORIGIN, post_id, Some(PostReaction::Like)),
+            ERROR_NOT_FORUM_USER
+        );
+    });
+}
+
+// Polls
+// -----------------------------------------------------------------------------
+
+#[test]
+fn vote_on_poll_successfully() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_sudo);
+        let member_origin = create_forum_member();
+        let thread_id = TestForumModule::next_thread_id();
+
+        CreateThreadFixture {
+            origin: member_origin.clone(),
+            category_id,
+            title: good_thread_title(),
+            text: good_thread_text(),
+            poll: Some(good_poll(10)),
+            result: Ok(()),
+        }
+        .call_and_assert();
+
+        assert_ok!(vote_on_poll(member_origin, thread_id, 1));
+        assert_ok!(vote_on_poll(create_forum_member_with_id(124), thread_id, 1));
+        assert_eq!(
+            TestForumModule::poll_vote_counts(thread_id),
+            Some(vec![0, 2])
+        );
+    });
+}
+
+#[test]
+fn cannot_vote_on_poll_twice() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_sudo);
+        let member_origin = create_forum_member();
+        let thread_id = TestForumModule::next_thread_id();
+
+        CreateThreadFixture {
+            origin: member_origin.clone(),
+            category_id,
+            title: good_thread_title(),
+            text: good_thread_text(),
+            poll: Some(good_poll(10)),
+            result: Ok(()),
+        }
+        .call_and_assert();
+
+        assert_ok!(vote_on_poll(member_origin.clone(), thread_id, 0));
+        assert_err!(
+            vote_on_poll(member_origin, thread_id, 1),
+            ERROR_ALREADY_VOTED_ON_POLL
+        );
+    });
+}
+
+#[test]
+fn cannot_vote_on_ended_poll() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_sudo);
+        let member_origin = create_forum_member();
+        let thread_id = TestForumModule::next_thread_id();
+
+        CreateThreadFixture {
+            origin: member_origin.clone(),
+            category_id,
+            title: good_thread_title(),
+            text: good_thread_text(),
+            poll: Some(good_poll(10)),
+            result: Ok(()),
+        }
+        .call_and_assert();
+
+        system::Module::<Runtime>::set_block_number(10);
+        assert_err!(vote_on_poll(member_origin, thread_id, 0), ERROR_POLL_ENDED);
+    });
+}
+
+#[test]
+fn cannot_vote_on_thread_without_poll() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo);
+        assert_err!(
+            vote_on_poll(member_origin, thread_id, 0),
+            ERROR_THREAD_HAS_NO_POLL
+        );
+    });
+}
+
+#[test]
+fn create_thread_poll_too_many_alternatives() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let max_alternatives = config.poll_alternatives_constraint.max() as usize;
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_sudo);
+        let mut poll = good_poll(10);
+        poll.alternatives = vec![b"Option".to_vec(); max_alternatives + 1];
+
+        CreateThreadFixture {
+            origin: create_forum_member(),
+            category_id,
+            title: good_thread_title(),
+            text: good_thread_text(),
+            poll: Some(poll),
+            result: Err(ERROR_POLL_TOO_MANY_ALTERNATIVES),
+        }
+        .call_and_assert();
+    });
+}
+
+// Deposits
+// -----------------------------------------------------------------------------
+
+#[test]
+fn create_thread_and_post_reserves_deposits() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let expected_reserved = config.thread_deposit + 2 * config.post_deposit;
+
+    build_test_externalities(config).execute_with(|| {
+        create_root_category_and_thread_and_post(forum_sudo);
+        assert_eq!(
+            Balances::reserved_balance(&FORUM_MEMBER_ID),
+            expected_reserved
+        );
+        assert_eq!(
+            Balances::free_balance(&FORUM_MEMBER_ID),
+            FORUM_MEMBER_BALANCE - expected_reserved
+        );
+    });
+}
+
+#[test]
+fn cannot_create_thread_without_balance_for_deposit() {
+    let mut config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    config.thread_deposit = FORUM_MEMBER_BALANCE + 1;
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_sudo);
+        assert_create_thread(
+            create_forum_member(),
+            category_id,
+            Err(ERROR_INSUFFICIENT_BALANCE_FOR_DEPOSIT),
+        );
+    });
+}
+
+#[test]
+fn delete_post_refunds_deposit() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let expected_reserved = config.thread_deposit + config.post_deposit;
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) = create_root_category_and_thread_and_post(forum_sudo);
+        assert_ok!(delete_post(member_origin, post_id));
+        assert_eq!(
+            Balances::reserved_balance(&FORUM_MEMBER_ID),
+            expected_reserved
+        );
+        assert_eq!(
+            Balances::free_balance(&FORUM_MEMBER_ID),
+            FORUM_MEMBER_BALANCE - expected_reserved
+        );
+    });
+}
+
+#[test]
+fn moderate_post_slashes_deposit() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let post_deposit = config.post_deposit;
+    let slash_amount = config.moderation_deposit_slash_ratio * post_deposit;
+    let expected_reserved = config.thread_deposit + post_deposit;
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, _, post_id) = create_root_category_and_thread_and_post(forum_sudo.clone());
+        assert_ok!(moderate_post(forum_sudo, post_id, good_rationale()));
+        assert_eq!(
+            Balances::reserved_balance(&FORUM_MEMBER_ID),
+            expected_reserved
+        );
+        assert_eq!(
+            Balances::free_balance(&FORUM_MEMBER_ID),
+            FORUM_MEMBER_BALANCE - expected_reserved - slash_amount
+        );
+    });
+}
+
+#[test]
+fn delete_thread_refunds_post_deposits_of_other_members() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let other_member_id = FORUM_MEMBER_ID + 1;
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo);
+        let other_member_origin = create_forum_member_with_id(other_member_id);
+        assert_create_post(other_member_origin, thread_id, Ok(()));
+
+        assert_ok!(delete_thread(member_origin, thread_id));
+        assert_eq!(Balances::reserved_balance(&other_member_id), 0);
+        assert_eq!(
+            Balances::free_balance(&other_member_id),
+            FORUM_MEMBER_BALANCE
+        );
+        assert_eq!(Balances::reserved_balance(&FORUM_MEMBER_ID), 0);
+    });
+}
+
+#[test]
+fn moderate_thread_slashes_post_deposits_of_author_and_refunds_others() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let slash_amount = config.moderation_deposit_slash_ratio * config.thread_deposit
+        + 2 * (config.moderation_deposit_slash_ratio * config.post_deposit);
+    let other_member_id = FORUM_MEMBER_ID + 1;
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id, _) = create_root_category_and_thread_and_post(forum_sudo.clone());
+        let other_member_origin = create_forum_member_with_id(other_member_id);
+        assert_create_post(other_member_origin, thread_id, Ok(()));
+
+        assert_ok!(moderate_thread(forum_sudo, thread_id, good_rationale()));
+        assert_eq!(Balances::reserved_balance(&other_member_id), 0);
+        assert_eq!(
+            Balances::free_balance(&other_member_id),
+            FORUM_MEMBER_BALANCE
+        );
+        assert_eq!(Balances::reserved_balance(&FORUM_MEMBER_ID), 0);
+        assert_eq!(
+            Balances::free_balance(&FORUM_MEMBER_ID),
+            FORUM_MEMBER_BALANCE - slash_amount
+        );
+    });
+}
+
+// Moving threads
+// -----------------------------------------------------------------------------
+
+#[test]
+fn move_thread_to_category_successfully() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, old_category_id, thread_id) =
+            create_root_category_and_thread(forum_sudo.clone());
+        let new_category_id = create_root_category(forum_sudo.clone());
+        assert_create_thread(member_origin.clone(), new_category_id, Ok(()));
+
+        assert_ok!(move_thread_to_category(
+            forum_sudo,
+            thread_id,
+            new_category_id
+        ));
+
+        let thread = TestForumModule::thread_by_id(thread_id);
+        assert_eq!(thread.category_id, new_category_id);
+        assert_eq!(thread.nr_in_category, 2);
+
+        let old_category = TestForumModule::category_by_id(old_category_id);
+        assert_eq!(old_category.num_direct_unmoderated_threads, 0);
+        assert_eq!(old_category.num_direct_moved_threads, 1);
+
+        let new_category = TestForumModule::category_by_id(new_category_id);
+        assert_eq!(new_category.num_direct_unmoderated_threads, 2);
+
+        // Threads added to old category still get a fresh number
+        let next_thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin, old_category_id, Ok(()));
+        assert_eq!(
+            TestForumModule::thread_by_id(next_thread_id).nr_in_category,
+            2
+        );
+    });
+}
+
+#[test]
+fn cannot_move_thread_to_archived_category() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+        let new_category_id = create_root_category(forum_sudo.clone());
+        assert_ok!(archive_category(forum_sudo.clone(), new_category_id));
+
+        assert_err!(
+            move_thread_to_category(forum_sudo, thread_id, new_category_id),
+            ERROR_ANCESTOR_CATEGORY_IMMUTABLE
+        );
+    });
+}
+
+#[test]
+fn category_moderator_cannot_move_thread_to_unmoderated_category() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, old_category_id, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+        let new_category_id = create_root_category(forum_sudo.clone());
+        assert_ok!(set_category_moderator(
+            forum_sudo,
+            old_category_id,
+            CATEGORY_MODERATOR_ID,
+            true
+        ));
+
+        assert_err!(
+            move_thread_to_category(CATEGORY_MODERATOR_ORIGIN, thread_id, new_category_id),
+            ERROR_ORIGIN_NOT_FORUM_SUDO_OR_CATEGORY_MODERATOR
+        );
+    });
+}
+
+// Editing thread titles
+// -----------------------------------------------------------------------------
+
+#[test]
+fn edit_thread_title_successfully() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+        let new_title = b"Better thread title".to_vec();
+
+        assert_ok!(edit_thread_title(
+            member_origin,
+            thread_id,
+            new_title.clone()
+        ));
+
+        // Moderators can also retitle
+        assert_ok!(edit_thread_title(
+            forum_sudo,
+            thread_id,
+            good_thread_title()
+        ));
+
+        let thread = TestForumModule::thread_by_id(thread_id);
+        assert_eq!(thread.title, good_thread_title());
+        assert_eq!(thread.title_change_history.len(), 2);
+        assert_eq!(thread.title_change_history[1].title, new_title);
+    });
+}
+
+#[test]
+fn thread_title_change_history_is_bounded() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo);
+
+        for _ in 0..MAX_THREAD_TITLE_CHANGE_HISTORY_LENGTH + 1 {
+            assert_ok!(edit_thread_title(
+                member_origin.clone(),
+                thread_id,
+                good_thread_title()
+            ));
+        }
+
+        let thread = TestForumModule::thread_by_id(thread_id);
+        assert_eq!(
+            thread.title_change_history.len(),
+            MAX_THREAD_TITLE_CHANGE_HISTORY_LENGTH
+        );
+    });
+}
+
+#[test]
+fn not_author_cannot_edit_thread_title() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread(forum_sudo);
+        assert_err!(
+            edit_thread_title(
+                create_forum_member_with_id(124),
+                thread_id,
+                good_thread_title()
+            ),
+            ERROR_ORIGIN_NOT_THREAD_AUTHOR_OR_MODERATOR
+        );
+    });
+}
+
+#[test]
+fn banned_author_cannot_edit_thread_title() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+        assert_ok!(sanction_account(
+            forum_sudo,
+            FORUM_MEMBER_ID,
+            SanctionKind::Ban,
+            None
+        ));
+        assert_err!(
+            edit_thread_title(member_origin, thread_id, good_thread_title()),
+            ERROR_ACCOUNT_BANNED
+        );
+    });
+}
+
+// Moving categories
+// -----------------------------------------------------------------------------
+
+#[test]
+fn move_category_successfully() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let old_parent_id = create_root_category(forum_sudo.clone());
+        let new_parent_id = create_root_category(forum_sudo.clone());
+        let category_id = create_category(forum_sudo.clone(), Some(old_parent_id));
+
+        assert_ok!(move_category(
+            forum_sudo.clone(),
+            category_id,
+            Some(new_parent_id)
+        ));
+
+        let category = TestForumModule::category_by_id(category_id);
+        assert_eq!(
+            category.position_in_parent_category,
+            Some(ChildPositionInParentCategory {
+                parent_id: new_parent_id,
+                child_nr_in_parent_category: 1,
+            })
+        );
+
+        let old_parent = TestForumModule::category_by_id(old_parent_id);
+        assert_eq!(old_parent.num_direct_subcategories, 0);
+        assert_eq!(old_parent.num_direct_moved_subcategories, 1);
+        assert_eq!(
+            TestForumModule::category_by_id(new_parent_id).num_direct_subcategories,
+            1
+        );
+
+        // Move to root
+        assert_ok!(move_category(forum_sudo, category_id, None));
+        assert!(TestForumModule::category_by_id(category_id)
+            .position_in_parent_category
+            .is_none());
+    });
+}
+
+#[test]
+fn cannot_move_category_into_its_subtree() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_sudo.clone());
+        let subcategory_id = create_category(forum_sudo.clone(), Some(category_id));
+
+        assert_err!(
+            move_category(forum_sudo, category_id, Some(subcategory_id)),
+            ERROR_CATEGORY_CANNOT_BE_MOVED_INTO_ITS_SUBTREE
+        );
+    });
+}
+
+#[test]
+fn cannot_move_category_beyond_max_depth() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let max_category_depth = config.max_category_depth;
+
+    build_test_externalities(config).execute_with(|| {
+        // Build a chain of categories of maximum depth
+        let mut parent_id = create_root_category(forum_sudo.clone());
+
+        for _ in 0..max_category_depth {
+            let category_id = create_root_category(forum_sudo.clone());
+            assert_ok!(move_category(
+                forum_sudo.clone(),
+                category_id,
+                Some(parent_id)
+            ));
+            parent_id = category_id;
+        }
+
+        let category_id = create_root_category(forum_sudo.clone());
+        assert_err!(
+            move_category(forum_sudo, category_id, Some(parent_id)),
+            ERROR_MAX_VALID_CATEGORY_DEPTH_EXCEEDED
+        );
+    });
+}
+
+#[test]
+fn not_forum_sudo_cannot_move_category() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_sudo.clone());
+        let new_parent_id = create_root_category(forum_sudo);
+
+        assert_err!(
+            move_category(NOT_FORUM_SUDO_ORIGIN, category_id, Some(new_parent_id)),
+            ERROR_ORIGIN_NOT_FORUM_SUDO
+        );
+    });
+}
+
+// Replies
+// -----------------------------------------------------------------------------
+
+#[test]
+fn reply_to_post_successfully() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id, post_id) =
+            create_root_category_and_thread_and_post(forum_sudo);
+        let reply_id = TestForumModule::next_post_id();
+
+        CreatePostFixture {
+            origin: member_origin,
+            thread_id,
+            text: good_post_text(),
+            reply_to: Some(post_id),
+            result: Ok(()),
+        }
+        .call_and_assert();
+
+        assert_eq!(
+            TestForumModule::post_by_id(reply_id).reply_to,
+            Some(post_id)
+        );
+        assert_eq!(TestForumModule::post_by_id(post_id).num_replies, 1);
+    });
+}
+
+#[test]
+fn cannot_reply_to_post_in_other_thread() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, category_id, _, post_id) =
+            create_root_category_and_thread_and_post(forum_sudo);
+        let other_thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin.clone(), category_id, Ok(()));
+
+        CreatePostFixture {
+            origin: member_origin,
+            thread_id: other_thread_id,
+            text: good_post_text(),
+            reply_to: Some(post_id),
+            result: Err(ERROR_REPLIED_POST_NOT_IN_THREAD),
+        }
+        .call_and_assert();
+    });
+}
+
+#[test]
+fn cannot_reply_to_invalid_post() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo);
+
+        CreatePostFixture {
+            origin: member_origin,
+            thread_id,
+            text: good_post_text(),
+            reply_to: Some(INVLAID_POST_ID),
+            result: Err(ERROR_POST_DOES_NOT_EXIST),
+        }
+        .call_and_assert();
+    });
+}
+
+// Pinned threads
+// -----------------------------------------------------------------------------
+
+#[test]
+fn pin_unpin_and_reorder_threads_successfully() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, category_id, first_thread_id) =
+            create_root_category_and_thread(forum_sudo.clone());
+        let second_thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin, category_id, Ok(()));
+
+        assert_ok!(pin_thread(forum_sudo.clone(), first_thread_id));
+        assert_ok!(pin_thread(forum_sudo.clone(), second_thread_id));
+        assert_eq!(
+            TestForumModule::pinned_threads_by_category(category_id),
+            vec![first_thread_id, second_thread_id]
+        );
+
+        assert_ok!(reorder_pinned_threads(
+            forum_sudo.clone(),
+            category_id,
+            vec![second_thread_id, first_thread_id]
+        ));
+        assert_eq!(
+            TestForumModule::pinned_threads_by_category(category_id),
+            vec![second_thread_id, first_thread_id]
+        );
+
+        assert_ok!(unpin_thread(forum_sudo, second_thread_id));
+        assert_eq!(
+            TestForumModule::pinned_threads_by_category(category_id),
+            vec![first_thread_id]
+        );
+    });
+}
+
+#[test]
+fn cannot_pin_more_than_max_pinned_threads() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_sudo.clone());
+        let member_origin = create_forum_member();
+
+        for _ in 0..MAX_PINNED_THREADS_PER_CATEGORY {
+            let thread_id = TestForumModule::next_thread_id();
+            assert_create_thread(member_origin.clone(), category_id, Ok(()));
+            assert_ok!(pin_thread(forum_sudo.clone(), thread_id));
+        }
+
+        let thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin, category_id, Ok(()));
+        assert_err!(
+            pin_thread(forum_sudo, thread_id),
+            ERROR_MAX_PINNED_THREADS_EXCEEDED
+        );
+    });
+}
+
+#[test]
+fn cannot_reorder_pinned_threads_with_invalid_order() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, category_id, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+        assert_ok!(pin_thread(forum_sudo.clone(), thread_id));
+
+        assert_err!(
+            reorder_pinned_threads(forum_sudo, category_id, vec![thread_id, thread_id]),
+            ERROR_PINNED_THREADS_ORDER_INVALID
+        );
+    });
+}
+
+#[test]
+fn moderated_thread_is_unpinned() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, category_id, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+        assert_ok!(pin_thread(forum_sudo.clone(), thread_id));
+        assert_ok!(moderate_thread(forum_sudo, thread_id, good_rationale()));
+        assert!(TestForumModule::pinned_threads_by_category(category_id).is_empty());
+    });
+}
+
+#[test]
+fn not_forum_sudo_cannot_pin_thread() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo);
+        assert_err!(
+            pin_thread(member_origin, thread_id),
+            ERROR_ORIGIN_NOT_FORUM_SUDO_OR_CATEGORY_MODERATOR
+        );
+    });
+}
+
+// Thread locking
+// -----------------------------------------------------------------------------
+
+#[test]
+fn cannot_add_or_edit_post_in_locked_thread() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, category_id, thread_id, post_id) =
+            create_root_category_and_thread_and_post(forum_sudo.clone());
+
+        assert_ok!(lock_thread(forum_sudo.clone(), thread_id));
+
+        assert_create_post(member_origin.clone(), thread_id, Err(ERROR_THREAD_LOCKED));
+        assert_err!(
+            TestForumModule::edit_post_text(mock_origin(member_origin), post_id, good_post_text()),
+            ERROR_THREAD_LOCKED
+        );
+
+        // Locking is not moderation
+        assert!(TestForumModule::thread_by_id(thread_id)
+            .moderation
+            .is_none());
+        assert_eq!(
+            TestForumModule::category_by_id(category_id).num_direct_moderated_threads,
+            0
+        );
+    });
+}
+
+#[test]
+fn can_add_post_after_thread_is_unlocked() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+
+        assert_ok!(lock_thread(forum_sudo.clone(), thread_id));
+        assert_err!(
+            lock_thread(forum_sudo.clone(), thread_id),
+            ERROR_THREAD_LOCKED
+        );
+        assert_ok!(unlock_thread(forum_sudo.clone(), thread_id));
+        assert_err!(
+            unlock_thread(forum_sudo, thread_id),
+            ERROR_THREAD_NOT_LOCKED
+        );
+
+        assert_create_post(member_origin, thread_id, Ok(()));
+    });
+}
+
+#[test]
+fn can_react_vote_and_delete_in_locked_thread() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_sudo.clone());
+        let member_origin = create_forum_member();
+        let thread_id = TestForumModule::next_thread_id();
+
+        CreateThreadFixture {
+            origin: member_origin.clone(),
+            category_id,
+            title: good_thread_title(),
+            text: good_thread_text(),
+            poll: Some(good_poll(10)),
+            result: Ok(()),
+        }
+        .call_and_assert();
+
+        let post_id = TestForumModule::next_post_id();
+        assert_create_post(member_origin.clone(), thread_id, Ok(()));
+
+        assert_ok!(lock_thread(forum_sudo, thread_id));
+
+        assert_ok!(react_to_post(
+            member_origin.clone(),
+            post_id,
+            Some(PostReaction::Upvote)
+        ));
+        assert_ok!(vote_on_poll(member_origin.clone(), thread_id, 1));
+        assert_ok!(delete_post(member_origin.clone(), post_id));
+        assert_ok!(delete_thread(member_origin, thread_id));
+    });
+}
+
+#[test]
+fn can_moderate_post_in_locked_thread() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id, post_id) =
+            create_root_category_and_thread_and_post(forum_sudo.clone());
+
+        assert_ok!(lock_thread(forum_sudo.clone(), thread_id));
+        assert_ok!(moderate_post(forum_sudo, post_id, good_rationale()));
+    });
+}
+
+#[test]
+fn not_forum_sudo_cannot_lock_thread() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo);
+        assert_err!(
+            lock_thread(member_origin, thread_id),
+            ERROR_ORIGIN_NOT_FORUM_SUDO_OR_CATEGORY_MODERATOR
+        );
+    });
+}
+
+// Unmoderation
+// -----------------------------------------------------------------------------
+
+#[test]
+fn unmoderate_thread_successfully() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, category_id, thread_id) =
+            create_root_category_and_thread(forum_sudo.clone());
+
+        assert_ok!(moderate_thread(
+            forum_sudo.clone(),
+            thread_id,
+            good_rationale()
+        ));
+        assert_ok!(unmoderate_thread(forum_sudo, thread_id, good_rationale()));
+
+        let thread = TestForumModule::thread_by_id(thread_id);
+        assert!(thread.moderation.is_none());
+        assert_eq!(thread.moderation_history.len(), 1);
+
+        let category = TestForumModule::category_by_id(category_id);
+        assert_eq!(category.num_direct_unmoderated_threads, 1);
+        assert_eq!(category.num_direct_moderated_threads, 0);
+
+        // Thread is mutable again
+        assert_create_post(member_origin, thread_id, Ok(()));
+    });
+}
+
+#[test]
+fn unmoderate_post_successfully() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id, post_id) =
+            create_root_category_and_thread_and_post(forum_sudo.clone());
+
+        assert_ok!(moderate_post(forum_sudo.clone(), post_id, good_rationale()));
+        assert_ok!(unmoderate_post(
+            forum_sudo.clone(),
+            post_id,
+            good_rationale()
+        ));
+        assert_ok!(moderate_post(forum_sudo, post_id, good_rationale()));
+
+        let post = TestForumModule::post_by_id(post_id);
+        assert!(post.moderation.is_some());
+        assert_eq!(post.moderation_history.len(), 1);
+
+        let thread = TestForumModule::thread_by_id(thread_id);
+        assert_eq!(thread.num_unmoderated_posts, 0);
+        assert_eq!(thread.num_moderated_posts, 1);
+    });
+}
+
+#[test]
+fn cannot_unmoderate_unmoderated_thread_or_post() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id, post_id) =
+            create_root_category_and_thread_and_post(forum_sudo.clone());
+
+        assert_err!(
+            unmoderate_thread(forum_sudo.clone(), thread_id, good_rationale()),
+            ERROR_THREAD_NOT_MODERATED
+        );
+        assert_err!(
+            unmoderate_post(forum_sudo, post_id, good_rationale()),
+            ERROR_POST_NOT_MODERATED
+        );
+    });
+}
+
+#[test]
+fn not_forum_sudo_cannot_unmoderate_thread() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+
+        assert_ok!(moderate_thread(forum_sudo, thread_id, good_rationale()));
+        assert_err!(
+            unmoderate_thread(member_origin, thread_id, good_rationale()),
+            ERROR_ORIGIN_NOT_FORUM_SUDO_OR_CATEGORY_MODERATOR
+        );
+    });
+}
+
+// Appeals
+// -----------------------------------------------------------------------------
+
+#[test]
+fn overturned_appeal_reverses_thread_moderation() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+
+        assert_ok!(moderate_thread(
+            forum_sudo.clone(),
+            thread_id,
+            good_rationale()
+        ));
+        assert_ok!(set_appeal_resolver(forum_sudo, APPEAL_RESOLVER_ID, true));
+
+        let appeal_id = TestForumModule::next_appeal_id();
+        assert_ok!(file_appeal(member_origin, AppealSubject::Thread(thread_id)));
+        assert_ok!(resolve_appeal(
+            APPEAL_RESOLVER_ORIGIN,
+            appeal_id,
+            AppealOutcome::Overturned
+        ));
+
+        let thread = TestForumModule::thread_by_id(thread_id);
+        assert!(thread.moderation.is_none());
+        assert_eq!(thread.moderation_history.len(), 1);
+
+        let resolution = TestForumModule::appeal_by_id(appeal_id).resolution.unwrap();
+        assert_eq!(resolution.outcome, AppealOutcome::Overturned);
+        assert_eq!(resolution.resolver_id, APPEAL_RESOLVER_ID);
+    });
+}
+
+#[test]
+fn upheld_appeal_keeps_post_moderation_and_cannot_be_refiled() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) =
+            create_root_category_and_thread_and_post(forum_sudo.clone());
+
+        assert_ok!(moderate_post(forum_sudo.clone(), post_id, good_rationale()));
+        assert_ok!(set_appeal_resolver(forum_sudo, APPEAL_RESOLVER_ID, true));
+
+        let appeal_id = TestForumModule::next_appeal_id();
+        assert_ok!(file_appeal(
+            member_origin.clone(),
+            AppealSubject::Post(post_id)
+        ));
+        assert_ok!(resolve_appeal(
+            APPEAL_RESOLVER_ORIGIN,
+            appeal_id,
+            AppealOutcome::Upheld
+        ));
+        assert_err!(
+            resolve_appeal(APPEAL_RESOLVER_ORIGIN, appeal_id, AppealOutcome::Overturned),
+            ERROR_APPEAL_ALREADY_RESOLVED
+        );
+
+        assert!(TestForumModule::post_by_id(post_id).moderation.is_some());
+        assert_err!(
+            file_appeal(member_origin, AppealSubject::Post(post_id)),
+            ERROR_MODERATION_ALREADY_APPEALED
+        );
+    });
+}
+
+#[test]
+fn original_moderator_cannot_resolve_appeal() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+
+        assert_ok!(moderate_thread(
+            forum_sudo.clone(),
+            thread_id,
+            good_rationale()
+        ));
+        assert_ok!(set_appeal_resolver(
+            forum_sudo.clone(),
+            default_genesis_config().forum_sudo,
+            true
+        ));
+
+        let appeal_id = TestForumModule::next_appeal_id();
+        assert_ok!(file_appeal(member_origin, AppealSubject::Thread(thread_id)));
+        assert_err!(
+            resolve_appeal(forum_sudo, appeal_id, AppealOutcome::Overturned),
+            ERROR_APPEAL_RESOLVER_IS_ORIGINAL_MODERATOR
+        );
+    });
+}
+
+#[test]
+fn cannot_appeal_after_appeal_window() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let appeal_window = config.appeal_window;
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+
+        assert_ok!(moderate_thread(forum_sudo, thread_id, good_rationale()));
+
+        let moderated_at = TestForumModule::thread_by_id(thread_id)
+            .moderation
+            .unwrap()
+            .moderated_at;
+
+        system::Module::<Runtime>::set_block_number(moderated_at.block + appeal_window + 1);
+
+        assert_err!(
+            file_appeal(member_origin, AppealSubject::Thread(thread_id)),
+            ERROR_APPEAL_WINDOW_EXPIRED
+        );
+    });
+}
+
+#[test]
+fn only_author_can_appeal() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+
+        assert_ok!(moderate_thread(forum_sudo, thread_id, good_rationale()));
+
+        let other_member_origin = create_forum_member_with_id(FORUM_MEMBER_ID + 1);
+        assert_err!(
+            file_appeal(other_member_origin, AppealSubject::Thread(thread_id)),
+            ERROR_ORIGIN_NOT_AUTHOR_OF_APPEAL_SUBJECT
+        );
+    });
+}
+
+#[test]
+fn banned_author_can_appeal() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+
+        assert_ok!(moderate_thread(
+            forum_sudo.clone(),
+            thread_id,
+            good_rationale()
+        ));
+        assert_ok!(sanction_account(
+            forum_sudo,
+            FORUM_MEMBER_ID,
+            SanctionKind::Ban,
+            None
+        ));
+
+        let appeal_id = TestForumModule::next_appeal_id();
+        assert_ok!(file_appeal(member_origin, AppealSubject::Thread(thread_id)));
+        assert_eq!(
+            TestForumModule::appeal_by_id(appeal_id).appellant_id,
+            FORUM_MEMBER_ID
+        );
+    });
+}
+
+// Post reports
+// -----------------------------------------------------------------------------
+
+#[test]
+fn post_is_flagged_when_report_threshold_is_reached() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let post_report_threshold = config.post_report_threshold;
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, _, post_id) = create_root_category_and_thread_and_post(forum_sudo.clone());
+
+        for i in 0..post_report_threshold {
+            assert!(!TestForumModule::is_flagged_post(post_id));
+
+            let reporter_origin = create_forum_member_with_id(FORUM_MEMBER_ID + 1 + i as u64);
+            assert_ok!(report_post(reporter_origin, post_id, None));
+        }
+
+        assert_eq!(
+            TestForumModule::post_reports(post_id).len(),
+            post_report_threshold as usize
+        );
+        assert!(TestForumModule::is_flagged_post(post_id));
+
+        // Moderation clears reports and flag
+        assert_ok!(moderate_post(forum_sudo, post_id, good_rationale()));
+        assert!(!TestForumModule::is_flagged_post(post_id));
+        assert!(TestForumModule::post_reports(post_id).is_empty());
+    });
+}
+
+#[test]
+fn flagged_posts_can_be_listed() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let post_report_threshold = config.post_report_threshold;
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id, first_post_id) =
+            create_root_category_and_thread_and_post(forum_sudo.clone());
+        let second_post_id = TestForumModule::next_post_id();
+        assert_create_post(member_origin, thread_id, Ok(()));
+
+        for i in 0..post_report_threshold {
+            let reporter_origin = create_forum_member_with_id(FORUM_MEMBER_ID + 1 + i as u64);
+            assert_ok!(report_post(reporter_origin.clone(), first_post_id, None));
+            assert_ok!(report_post(reporter_origin, second_post_id, None));
+        }
+
+        let mut flagged_posts = TestForumModule::flagged_posts();
+        flagged_posts.sort();
+        assert_eq!(flagged_posts, vec![first_post_id, second_post_id]);
+
+        assert_ok!(moderate_post(forum_sudo, first_post_id, good_rationale()));
+        assert_eq!(TestForumModule::flagged_posts(), vec![second_post_id]);
+    });
+}
+
+#[test]
+fn moderate_thread_clears_reports_of_its_posts() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let post_report_threshold = config.post_report_threshold;
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id, post_id) =
+            create_root_category_and_thread_and_post(forum_sudo.clone());
+
+        for i in 0..post_report_threshold {
+            let reporter_origin = create_forum_member_with_id(FORUM_MEMBER_ID + 1 + i as u64);
+            assert_ok!(report_post(reporter_origin, post_id, None));
+        }
+        assert!(TestForumModule::is_flagged_post(post_id));
+
+        assert_ok!(moderate_thread(forum_sudo, thread_id, good_rationale()));
+        assert!(!TestForumModule::is_flagged_post(post_id));
+        assert!(TestForumModule::post_reports(post_id).is_empty());
+    });
+}
+
+#[test]
+fn cannot_set_post_report_threshold_above_max_reports() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        assert_err!(
+            set_post_report_threshold(OriginType::Root, MAX_REPORTS_PER_POST as u32 + 1),
+            ERROR_POST_REPORT_THRESHOLD_TOO_HIGH
+        );
+        assert_ok!(set_post_report_threshold(
+            OriginType::Root,
+            MAX_REPORTS_PER_POST as u32
+        ));
+        assert_eq!(
+            TestForumModule::post_report_threshold(),
+            MAX_REPORTS_PER_POST as u32
+        );
+    });
+}
+
+#[test]
+fn cannot_report_post_twice() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) = create_root_category_and_thread_and_post(forum_sudo);
+
+        assert_ok!(report_post(
+            member_origin.clone(),
+            post_id,
+            Some(b"Selling things".to_vec())
+        ));
+        assert_err!(
+            report_post(member_origin, post_id, None),
+            ERROR_POST_ALREADY_REPORTED_BY_ACCOUNT
+        );
+    });
+}
+
+#[test]
+fn cannot_report_post_with_too_long_text() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let text_constraint = config.post_report_text_constraint.clone();
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) = create_root_category_and_thread_and_post(forum_sudo);
+
+        assert_err!(
+            report_post(
+                member_origin,
+                post_id,
+                Some(generate_text(text_constraint.max() as usize + 1))
+            ),
+            ERROR_POST_REPORT_TEXT_TOO_LONG
+        );
+    });
+}
+
+// Rate limits
+// -----------------------------------------------------------------------------
+
+#[test]
+fn cannot_exceed_post_rate_limit() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let max_posts = config.max_posts_per_rate_limit_period;
+    let period = config.post_rate_limit_period;
+
+    build_test_externalities(config).execute_with(|| {
+        // Initial post of thread counts towards limit
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo);
+
+        for _ in 1..max_posts {
+            assert_create_post(member_origin.clone(), thread_id, Ok(()));
+        }
+
+        assert_create_post(
+            member_origin.clone(),
+            thread_id,
+            Err(ERROR_POST_RATE_LIMIT_EXCEEDED),
+        );
+
+        // Posts expire from rate limit period
+        let current_block = system::Module::<Runtime>::block_number();
+        system::Module::<Runtime>::set_block_number(current_block + period);
+
+        assert_create_post(member_origin, thread_id, Ok(()));
+    });
+}
+
+#[test]
+fn cannot_create_threads_too_frequently() {
+    let mut config = default_genesis_config();
+    config.min_blocks_between_threads = 5;
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, category_id, _) = create_root_category_and_thread(forum_sudo);
+
+        assert_create_thread(
+            member_origin.clone(),
+            category_id,
+            Err(ERROR_THREAD_RATE_LIMIT_EXCEEDED),
+        );
+
+        let current_block = system::Module::<Runtime>::block_number();
+        system::Module::<Runtime>::set_block_number(current_block + 5);
+
+        assert_create_thread(member_origin, category_id, Ok(()));
+    });
+}
+
+#[test]
+fn post_rate_limit_is_per_account() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let max_posts = config.max_posts_per_rate_limit_period;
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo);
+
+        for _ in 1..max_posts {
+            assert_create_post(member_origin.clone(), thread_id, Ok(()));
+        }
+
+        let other_member_origin = create_forum_member_with_id(FORUM_MEMBER_ID + 1);
+        assert_create_post(other_member_origin, thread_id, Ok(()));
+    });
+}
+
+// Sanctions
+// -----------------------------------------------------------------------------
+
+#[test]
+fn banned_account_cannot_post_until_ban_is_lifted() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+
+        assert_ok!(sanction_account(
+            forum_sudo.clone(),
+            FORUM_MEMBER_ID,
+            SanctionKind::Ban,
+            None
+        ));
+        assert_create_post(member_origin.clone(), thread_id, Err(ERROR_ACCOUNT_BANNED));
+        assert_eq!(TestForumModule::active_sanctions().len(), 1);
+        assert!(TestForumModule::is_sanctioned_account(FORUM_MEMBER_ID));
+
+        assert_ok!(lift_sanction(forum_sudo, FORUM_MEMBER_ID));
+        assert_create_post(member_origin, thread_id, Ok(()));
+        assert!(TestForumModule::active_sanctions().is_empty());
+        assert!(!TestForumModule::is_sanctioned_account(FORUM_MEMBER_ID));
+    });
+}
+
+#[test]
+fn suspension_expires() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+
+        let current_block = system::Module::<Runtime>::block_number();
+        assert_ok!(sanction_account(
+            forum_sudo,
+            FORUM_MEMBER_ID,
+            SanctionKind::Suspension,
+            Some(current_block + 10)
+        ));
+        assert_create_post(
+            member_origin.clone(),
+            thread_id,
+            Err(ERROR_ACCOUNT_SUSPENDED),
+        );
+
+        system::Module::<Runtime>::set_block_number(current_block + 10);
+
+        assert!(TestForumModule::active_sanctions().is_empty());
+        assert_create_post(member_origin, thread_id, Ok(()));
+    });
+}
+
+#[test]
+fn suspension_requires_expiry() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        assert_err!(
+            sanction_account(forum_sudo, FORUM_MEMBER_ID, SanctionKind::Suspension, None),
+            ERROR_SUSPENSION_WITHOUT_EXPIRY
+        );
+        assert!(TestForumModule::sanction_by_account(FORUM_MEMBER_ID).is_none());
+    });
+}
+
+#[test]
+fn category_moderator_can_sanction_account() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_sudo.clone());
+        assert_ok!(set_category_moderator(
+            forum_sudo,
+            category_id,
+            CATEGORY_MODERATOR_ID,
+            true
+        ));
+
+        assert_ok!(sanction_account(
+            CATEGORY_MODERATOR_ORIGIN,
+            FORUM_MEMBER_ID,
+            SanctionKind::Ban,
+            None
+        ));
+        assert_err!(
+            sanction_account(
+                CATEGORY_MODERATOR_ORIGIN,
+                default_genesis_config().forum_sudo,
+                SanctionKind::Ban,
+                None
+            ),
+            ERROR_CANNOT_SANCTION_FORUM_SUDO
+        );
+    });
+}
+
+#[test]
+fn category_moderator_cannot_sanction_other_moderator() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_sudo.clone());
+        let subcategory_id = create_category(forum_sudo.clone(), Some(category_id));
+        assert_ok!(set_category_moderator(
+            forum_sudo.clone(),
+            subcategory_id,
+            CATEGORY_MODERATOR_ID,
+            true
+        ));
+        assert_ok!(set_category_moderator(
+            forum_sudo,
+            category_id,
+            FORUM_MEMBER_ID,
+            true
+        ));
+
+        assert_err!(
+            sanction_account(
+                CATEGORY_MODERATOR_ORIGIN,
+                FORUM_MEMBER_ID,
+                SanctionKind::Ban,
+                None
+            ),
+            ERROR_CANNOT_SANCTION_CATEGORY_MODERATOR
+        );
+    });
+}
+
+#[test]
+fn category_moderator_cannot_lift_sanction_of_forum_sudo() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_sudo.clone());
+        assert_ok!(set_category_moderator(
+            forum_sudo.clone(),
+            category_id,
+            CATEGORY_MODERATOR_ID,
+            true
+        ));
+        assert_ok!(sanction_account(
+            forum_sudo,
+            FORUM_MEMBER_ID,
+            SanctionKind::Ban,
+            None
+        ));
+
+        assert_err!(
+            lift_sanction(CATEGORY_MODERATOR_ORIGIN, FORUM_MEMBER_ID),
+            ERROR_SANCTION_NOT_IMPOSED_BY_ORIGIN
+        );
+        assert_err!(
+            sanction_account(
+                CATEGORY_MODERATOR_ORIGIN,
+                FORUM_MEMBER_ID,
+                SanctionKind::Suspension,
+                Some(100)
+            ),
+            ERROR_SANCTION_NOT_IMPOSED_BY_ORIGIN
+        );
+    });
+}
+
+#[test]
+fn removed_category_moderator_cannot_sanction_account() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_sudo.clone());
+        assert_ok!(set_category_moderator(
+            forum_sudo.clone(),
+            category_id,
+            CATEGORY_MODERATOR_ID,
+            true
+        ));
+        assert_ok!(set_category_moderator(
+            forum_sudo,
+            category_id,
+            CATEGORY_MODERATOR_ID,
+            false
+        ));
+
+        assert_err!(
+            sanction_account(
+                CATEGORY_MODERATOR_ORIGIN,
+                FORUM_MEMBER_ID,
+                SanctionKind::Ban,
+                None
+            ),
+            ERROR_ORIGIN_NOT_FORUM_SUDO_OR_ANY_CATEGORY_MODERATOR
+        );
+    });
+}
+
+#[test]
+fn not_moderator_cannot_sanction_account() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let member_origin = create_forum_member();
+        assert_err!(
+            sanction_account(member_origin, 124, SanctionKind::Ban, None),
+            ERROR_ORIGIN_NOT_FORUM_SUDO_OR_ANY_CATEGORY_MODERATOR
+        );
+    });
+}
+
+// Posting policies
+// -----------------------------------------------------------------------------
+
+#[test]
+fn only_moderators_can_create_threads_in_moderators_only_category() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, category_id, thread_id) =
+            create_root_category_and_thread(forum_sudo.clone());
+
+        assert_ok!(set_category_posting_policy(
+            forum_sudo.clone(),
+            category_id,
+            PostingPolicy::ModeratorsOnlyThreads
+        ));
+        assert_ok!(set_category_moderator(
+            forum_sudo,
+            category_id,
+            CATEGORY_MODERATOR_ID,
+            true
+        ));
+
+        assert_create_thread(
+            member_origin.clone(),
+            category_id,
+            Err(ERROR_THREADS_RESTRICTED_TO_MODERATORS),
+        );
+
+        let moderator_origin = create_forum_member_with_id(CATEGORY_MODERATOR_ID);
+        assert_create_thread(moderator_origin, category_id, Ok(()));
+
+        // Members can still post in existing threads
+        assert_create_post(member_origin, thread_id, Ok(()));
+    });
+}
+
+#[test]
+fn only_allowlisted_members_can_post_in_restricted_category() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, category_id, thread_id) =
+            create_root_category_and_thread(forum_sudo.clone());
+
+        assert_ok!(set_category_posting_policy(
+            forum_sudo.clone(),
+            category_id,
+            PostingPolicy::Restricted
+        ));
+
+        assert_create_post(
+            member_origin.clone(),
+            thread_id,
+            Err(ERROR_ACCOUNT_NOT_ON_CATEGORY_POSTING_ALLOWLIST),
+        );
+        assert_create_thread(
+            member_origin.clone(),
+            category_id,
+            Err(ERROR_ACCOUNT_NOT_ON_CATEGORY_POSTING_ALLOWLIST),
+        );
+
+        assert_ok!(set_category_posting_allowlist(
+            forum_sudo,
+            category_id,
+            FORUM_MEMBER_ID,
+            true
+        ));
+
+        assert_create_post(member_origin.clone(), thread_id, Ok(()));
+        assert_create_thread(member_origin, category_id, Ok(()));
+    });
+}
+
+#[test]
+fn not_forum_sudo_cannot_set_posting_policy() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, category_id, _) = create_root_category_and_thread(forum_sudo);
+
+        assert_err!(
+            set_category_posting_policy(member_origin, category_id, PostingPolicy::Restricted),
+            ERROR_ORIGIN_NOT_FORUM_SUDO_OR_CATEGORY_MODERATOR
+        );
+    });
+}
+
+// Forum users
+// -----------------------------------------------------------------------------
+
+#[test]
+fn inactive_forum_user_cannot_post() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo);
+
+        registry::TestMembershipRegistryModule::set_member_active(&FORUM_MEMBER_ID, false);
+        assert_create_post(
+            member_origin.clone(),
+            thread_id,
+            Err(ERROR_FORUM_USER_NOT_ACTIVE),
+        );
+
+        registry::TestMembershipRegistryModule::set_member_active(&FORUM_MEMBER_ID, true);
+        assert_create_post(member_origin, thread_id, Ok(()));
+    });
+}
+
+#[test]
+fn forum_user_has_only_its_roles() {
+    let forum_user = ForumUser {
+        id: FORUM_MEMBER_ID,
+        handle: b"alice".to_vec(),
+        roles: vec![registry::MemberRole::CouncilMember],
+        is_active: true,
+    };
+
+    assert!(forum_user.has_role(&registry::MemberRole::CouncilMember));
+    assert!(!forum_user.has_role(&registry::MemberRole::WorkingGroupLead));
+}
+
+// Thread and post indices
+// -----------------------------------------------------------------------------
+
+#[test]
+fn threads_and_posts_are_indexed_by_number() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, category_id, thread_id, first_post_id) =
+            create_root_category_and_thread_and_post(forum_sudo);
+
+        let second_thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin, category_id, Ok(()));
+
+        assert_eq!(
+            TestForumModule::thread_id_by_category_and_nr(category_id, 1u32),
+            Some(thread_id)
+        );
+        assert_eq!(
+            TestForumModule::thread_id_by_category_and_nr(category_id, 2u32),
+            Some(second_thread_id)
+        );
+        assert_eq!(
+            TestForumModule::thread_id_by_category_and_nr(category_id, 3u32),
+            None
+        );
+
+        let initial_post_id = first_post_id - 1;
+        assert_eq!(
+            TestForumModule::post_id_by_thread_and_nr(thread_id, 1u32),
+            Some(initial_post_id)
+        );
+        assert_eq!(
+            TestForumModule::post_id_by_thread_and_nr(thread_id, 2u32),
+            Some(first_post_id)
+        );
+    });
+}
+
+#[test]
+fn moved_thread_is_reindexed() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, category_id, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+        let new_category_id = create_root_category(forum_sudo.clone());
+
+        assert_ok!(move_thread_to_category(
+            forum_sudo,
+            thread_id,
+            new_category_id
+        ));
+
+        assert_eq!(
+            TestForumModule::thread_id_by_category_and_nr(category_id, 1u32),
+            None
+        );
+        assert_eq!(
+            TestForumModule::thread_id_by_category_and_nr(new_category_id, 1u32),
+            Some(thread_id)
+        );
+    });
+}
+
+// Category tree index
+// -----------------------------------------------------------------------------
+
+#[test]
+fn categories_are_indexed_by_parent() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let root_category_id = create_root_category(forum_sudo.clone());
+        let other_root_category_id = create_root_category(forum_sudo.clone());
+        let first_subcategory_id = create_category(forum_sudo.clone(), Some(root_category_id));
+        let second_subcategory_id = create_category(forum_sudo, Some(root_category_id));
+
+        assert_eq!(
+            TestForumModule::root_category_ids(),
+            vec![root_category_id, other_root_category_id]
+        );
+        assert_eq!(
+            TestForumModule::subcategory_ids(root_category_id),
+            vec![first_subcategory_id, second_subcategory_id]
+        );
+        assert_eq!(
+            TestForumModule::category_id_by_parent_and_nr(root_category_id, 2u32),
+            Some(second_subcategory_id)
+        );
+    });
+}
+
+#[test]
+fn moved_category_is_reindexed() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let root_category_id = create_root_category(forum_sudo.clone());
+        let other_root_category_id = create_root_category(forum_sudo.clone());
+        let subcategory_id = create_category(forum_sudo.clone(), Some(root_category_id));
+
+        assert_ok!(move_category(
+            forum_sudo.clone(),
+            subcategory_id,
+            Some(other_root_category_id)
+        ));
+        assert!(TestForumModule::subcategory_ids(root_category_id).is_empty());
+        assert_eq!(
+            TestForumModule::subcategory_ids(other_root_category_id),
+            vec![subcategory_id]
+        );
+
+        assert_ok!(move_category(forum_sudo, subcategory_id, None));
+        assert!(TestForumModule::subcategory_ids(other_root_category_id).is_empty());
+        assert_eq!(
+            TestForumModule::root_category_ids(),
+            vec![root_category_id, other_root_category_id, subcategory_id]
+        );
+    });
+}
+
+// Author index
+// -----------------------------------------------------------------------------
+
+#[test]
+fn threads_and_posts_are_indexed_by_author() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id, post_id) = create_root_category_and_thread_and_post(forum_sudo);
+        let initial_post_id = post_id - 1;
+
+        assert_eq!(
+            TestForumModule::num_threads_created_by_author(FORUM_MEMBER_ID),
+            1
+        );
+        assert_eq!(
+            TestForumModule::thread_id_by_author_and_nr(FORUM_MEMBER_ID, 1u32),
+            Some(thread_id)
+        );
+        assert_eq!(
+            TestForumModule::num_posts_created_by_author(FORUM_MEMBER_ID),
+            2
+        );
+        assert_eq!(
+            TestForumModule::post_id_by_author_and_nr(FORUM_MEMBER_ID, 1u32),
+            Some(initial_post_id)
+        );
+        assert_eq!(
+            TestForumModule::post_id_by_author_and_nr(FORUM_MEMBER_ID, 2u32),
+            Some(post_id)
+        );
+        assert_eq!(
+            TestForumModule::posts_by_author(&FORUM_MEMBER_ID, 1, 10).len(),
+            2
+        );
+    });
+}
+
+#[test]
+fn deleted_threads_and_posts_are_removed_from_author_index() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id, post_id) =
+            create_root_category_and_thread_and_post(forum_sudo);
+
+        assert_ok!(delete_post(member_origin.clone(), post_id));
+        assert_eq!(
+            TestForumModule::post_id_by_author_and_nr(FORUM_MEMBER_ID, 2u32),
+            None
+        );
+        assert_eq!(
+            TestForumModule::posts_by_author(&FORUM_MEMBER_ID, 1, 10).len(),
+            1
+        );
+
+        assert_ok!(delete_thread(member_origin, thread_id));
+        assert!(TestForumModule::threads_by_author(&FORUM_MEMBER_ID, 1, 10).is_empty());
+
+        // Numbers by author are not reused
+        assert_eq!(
+            TestForumModule::num_posts_created_by_author(FORUM_MEMBER_ID),
+            2
+        );
+    });
+}
+
+#[test]
+fn genesis_categories_threads_and_posts_are_indexed() {
+    let mut config = default_genesis_config();
+    config.category_by_id = vec![
+        (
+            1,
+            RuntimeCategory {
+                id: 1,
+                num_direct_subcategories: 1,
+                num_direct_unmoderated_threads: 1,
+                ..Default::default()
+            },
+        ),
+        (
+            2,
+            RuntimeCategory {
+                id: 2,
+                position_in_parent_category: Some(ChildPositionInParentCategory {
+                    parent_id: 1,
+                    child_nr_in_parent_category: 1,
+                }),
+                ..Default::default()
+            },
+        ),
+    ];
+    config.next_category_id = 3;
+    config.thread_by_id = vec![(
+        1,
+        RuntimeThread {
+            id: 1,
+            category_id: 1,
+            nr_in_category: 1,
+            nr_by_author: 1,
+            num_unmoderated_posts: 1,
+            author_id: FORUM_MEMBER_ID,
+            ..Default::default()
+        },
+    )];
+    config.next_thread_id = 2;
+    config.post_by_id = vec![(
+        1,
+        RuntimePost {
+            id: 1,
+            thread_id: 1,
+            nr_in_thread: 1,
+            nr_by_author: 1,
+            author_id: FORUM_MEMBER_ID,
+            ..Default::default()
+        },
+    )];
+    config.next_post_id = 2;
+
+    build_test_externalities(config).execute_with(|| {
+        assert_eq!(TestForumModule::root_category_ids(), vec![1]);
+        assert_eq!(TestForumModule::subcategory_ids(1), vec![2]);
+        assert_eq!(
+            TestForumModule::thread_id_by_category_and_nr(1, 1u32),
+            Some(1)
+        );
+        assert_eq!(TestForumModule::post_id_by_thread_and_nr(1, 1u32), Some(1));
+        assert_eq!(
+            TestForumModule::thread_id_by_author_and_nr(FORUM_MEMBER_ID, 1u32),
+            Some(1)
+        );
+        assert_eq!(
+            TestForumModule::num_posts_created_by_author(FORUM_MEMBER_ID),
+            1
+        );
+    });
+}
+
+// Read queries
+// -----------------------------------------------------------------------------
+
+#[test]
+fn threads_in_category_are_paginated() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, category_id, first_thread_id) =
+            create_root_category_and_thread(forum_sudo.clone());
+        let second_thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin.clone(), category_id, Ok(()));
+        let third_thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin, category_id, Ok(()));
+
+        // Moved threads leave a gap in numbering
+        let other_category_id = create_root_category(forum_sudo.clone());
+        assert_ok!(move_thread_to_category(
+            forum_sudo,
+            second_thread_id,
+            other_category_id
+        ));
+
+        let page_ids = |first_nr, max| {
+            TestForumModule::threads_in_category(category_id, first_nr, max)
+                .iter()
+                .map(|thread| thread.id)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(page_ids(1, 2), vec![first_thread_id]);
+        assert_eq!(page_ids(3, 2), vec![third_thread_id]);
+        assert_eq!(page_ids(1, 10), vec![first_thread_id, third_thread_id]);
+
+        let counters = TestForumModule::category_counters(category_id).unwrap();
+        assert_eq!(counters.num_direct_unmoderated_threads, 2);
+        assert_eq!(counters.num_direct_moved_threads, 1);
+        assert_eq!(counters.num_threads_created, 3);
+    });
+}
+
+#[test]
+fn posts_in_thread_are_paginated() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id, post_id) = create_root_category_and_thread_and_post(forum_sudo);
+
+        let posts = TestForumModule::posts_in_thread(thread_id, 2, 5);
+        assert_eq!(posts.len(), 1);
+        assert_eq!(posts[0].id, post_id);
+
+        let counters = TestForumModule::thread_counters(thread_id).unwrap();
+        assert_eq!(counters.num_posts_ever_created, 2);
+        assert!(TestForumModule::thread_counters(INVLAID_THREAD_ID).is_none());
+    });
+}
+
+#[test]
+fn category_tree_path_is_queried() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let root_category_id = create_root_category(forum_sudo.clone());
+        let subcategory_id = create_category(forum_sudo, Some(root_category_id));
+
+        let path_ids = TestForumModule::category_tree_path(subcategory_id)
+            .iter()
+            .map(|category| category.id)
+            .collect::<Vec<_>>();
+
+        assert_eq!(path_ids, vec![subcategory_id, root_category_id]);
+        assert!(TestForumModule::category_tree_path(INVLAID_CATEGORY_ID).is_empty());
+        assert_eq!(TestForumModule::subcategories(root_category_id).len(), 1);
+    });
+}
+
+#[test]
+fn query_results_round_trip_through_codec() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, category_id, thread_id, _) = create_root_category_and_thread_and_post(forum_sudo);
+
+        let path = TestForumModule::category_tree_path(category_id);
+        let threads = TestForumModule::threads_in_category(category_id, 1, 10);
+        let posts = TestForumModule::posts_in_thread(thread_id, 1, 10);
+
+        assert_eq!(Vec::<_>::decode(&mut &path.encode()[..]).ok(), Some(path));
+        assert_eq!(
+            Vec::<_>::decode(&mut &threads.encode()[..]).ok(),
+            Some(threads)
+        );
+        assert_eq!(Vec::<_>::decode(&mut &posts.encode()[..]).ok(), Some(posts));
+    });
+}
+
+// Events
+// -----------------------------------------------------------------------------
+
+#[test]
+fn create_thread_generates_events_with_author_and_text_hashes() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let initial_post_id = TestForumModule::next_post_id();
+        let (_, category_id, thread_id) = create_root_category_and_thread(forum_sudo);
+
+        let events = forum_events();
+        assert!(events.contains(&RawEvent::ThreadCreated(
+            thread_id,
+            FORUM_MEMBER_ID,
+            category_id,
+            <Runtime as system::Trait>::Hashing::hash(&good_thread_title()),
+        )));
+        assert_eq!(
+            events.last(),
+            Some(&RawEvent::PostAdded(
+                initial_post_id,
+                FORUM_MEMBER_ID,
+                thread_id,
+                <Runtime as system::Trait>::Hashing::hash(&good_thread_text()),
+            ))
+        );
+    });
+}
+
+#[test]
+fn moderation_generates_events_with_moderator_and_parent() {
+    let config = default_genesis_config();
+    let forum_sudo_id = config.forum_sudo;
+    let forum_sudo = OriginType::Signed(forum_sudo_id);
+    let rationale_hash = <Runtime as system::Trait>::Hashing::hash(&good_rationale());
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, category_id, thread_id, post_id) =
+            create_root_category_and_thread_and_post(forum_sudo.clone());
+
+        assert_ok!(moderate_post(forum_sudo.clone(), post_id, good_rationale()));
+        assert_eq!(
+            forum_events().last(),
+            Some(&RawEvent::PostModerated(
+                post_id,
+                forum_sudo_id,
+                thread_id,
+                rationale_hash
+            ))
+        );
+
+        assert_ok!(moderate_thread(forum_sudo, thread_id, good_rationale()));
+        assert_eq!(
+            forum_events().last(),
+            Some(&RawEvent::ThreadModerated(
+                thread_id,
+                forum_sudo_id,
+                category_id,
+                rationale_hash
+            ))
+        );
+    });
+}